@@ -0,0 +1,100 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::set_dummy;
+use quote::quote;
+use structmeta::{Flag, NameValue, StructMeta};
+use syn::{Attribute, Data, DeriveInput, Visibility};
+
+use crate::{builder, compat, getter, methods::Target, setter, Options};
+
+/// The arguments of `#[accessors(get, set, fields = pub(crate))]`.
+#[derive(Clone, Debug, Default, StructMeta)]
+pub struct Args {
+    pub get: Flag,
+    pub set: Flag,
+    pub builder: Flag,
+    pub fields: Option<NameValue<Visibility>>,
+}
+
+/// The helper attributes of the derives, which are consumed by the attribute macro.
+const HELPER_ATTRS: &[&str] = &["builder", "get", "get_copy", "get_mut", "getset", "set"];
+
+/// Generate the accessors like the derives, and rewrite the structure to hide its fields behind them.
+pub fn expand(args: Args, input: DeriveInput, options: &Options) -> TokenStream {
+    let item = rewrite(
+        input.clone(),
+        args.fields
+            .map_or(Visibility::Inherited, |fields| fields.value),
+    );
+
+    // keep the structure if the expansion is aborted
+    set_dummy(quote! { #item });
+
+    // the getters and setters are generated unless some of the derives are chosen
+    let all = !(args.get.value() || args.set.value() || args.builder.value());
+    let mut tokens = TokenStream::new();
+
+    if all || args.get.value() {
+        let mut input = input.clone();
+
+        if options.compat {
+            compat::translate(&mut input, compat::Kind::GETTERS);
+        }
+
+        tokens.extend(getter::expand(input, options, &Target::default()));
+    }
+
+    if all || args.set.value() {
+        let mut input = input.clone();
+
+        if options.compat {
+            compat::translate(&mut input, compat::Kind::SETTERS);
+        }
+
+        tokens.extend(setter::expand(input, options, &Target::default()));
+    }
+
+    if args.builder.value() {
+        let mut input = input;
+
+        if options.compat {
+            compat::translate(&mut input, compat::Kind::SETTERS);
+        }
+
+        tokens.extend(builder::expand(input, options));
+    }
+
+    quote! {
+        #item
+        #tokens
+    }
+}
+
+/// Strip the helper attributes, and demote the fields of a structure to the visibility.
+fn rewrite(mut input: DeriveInput, vis: Visibility) -> DeriveInput {
+    strip_helper_attrs(&mut input.attrs);
+
+    match input.data {
+        Data::Struct(ref mut data) => {
+            for field in data.fields.iter_mut() {
+                strip_helper_attrs(&mut field.attrs);
+                field.vis = vis.clone();
+            }
+        }
+        Data::Enum(ref mut data) => {
+            for variant in data.variants.iter_mut() {
+                strip_helper_attrs(&mut variant.attrs);
+
+                for field in variant.fields.iter_mut() {
+                    strip_helper_attrs(&mut field.attrs);
+                }
+            }
+        }
+        Data::Union(_) => {}
+    }
+
+    input
+}
+
+fn strip_helper_attrs(attrs: &mut Vec<Attribute>) {
+    attrs.retain(|attr| !HELPER_ATTRS.iter().any(|name| attr.path().is_ident(name)));
+}
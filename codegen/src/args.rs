@@ -0,0 +1,765 @@
+use merge::Merge;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use proc_macro_error::emit_error;
+use quote::format_ident;
+use structmeta::{Flag, NameArgs, NameValue};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned,
+    AttrStyle, Attribute, ExprPath, Ident, LitBool, LitStr, Meta, Path, Token, Type, Visibility,
+};
+
+use crate::{
+    vis::{AsVisibility, Restricted},
+    Options,
+};
+
+pub trait AsBool {
+    fn as_bool(&self) -> Option<bool>;
+
+    fn bool(&self) -> bool {
+        self.as_bool().unwrap_or_default()
+    }
+}
+
+impl AsBool for Flag {
+    fn as_bool(&self) -> Option<bool> {
+        self.span.map(|_| true)
+    }
+}
+
+impl AsBool for Option<NameArgs<Option<LitBool>>> {
+    fn as_bool(&self) -> Option<bool> {
+        if let Some(v) = self {
+            v.args.as_ref().map(|v| v.value).or(Some(true))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> AsBool for Option<NameArgs<Option<Toggle<T>>>> {
+    fn as_bool(&self) -> Option<bool> {
+        self.as_ref().map(|arg| match arg.args {
+            Some(Toggle::Bool(ref b)) => b.value,
+            _ => true,
+        })
+    }
+}
+
+impl AsBool for Option<NameArgs<Option<ExprPath>>> {
+    fn as_bool(&self) -> Option<bool> {
+        self.as_ref().map(|_| true)
+    }
+}
+
+impl AsBool for Option<NameArgs<Option<Type>>> {
+    fn as_bool(&self) -> Option<bool> {
+        self.as_ref().map(|_| true)
+    }
+}
+
+impl AsBool for Option<NameArgs<Type>> {
+    fn as_bool(&self) -> Option<bool> {
+        self.as_ref().map(|_| true)
+    }
+}
+
+pub trait AsSpan {
+    fn as_span(&self) -> Option<Span>;
+}
+
+impl AsSpan for Flag {
+    fn as_span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+impl<T> AsSpan for Option<NameArgs<T>> {
+    fn as_span(&self) -> Option<Span> {
+        self.as_ref().map(|arg| arg.name_span)
+    }
+}
+
+/// A mode which is exclusive with the other modes, like `copy` and `clone` of getter.
+pub struct Mode {
+    pub name: &'static str,
+    pub applied: bool,
+    pub span: Option<Span>,
+}
+
+impl Mode {
+    pub fn new<F: AsSpan, S: AsSpan>(
+        name: &'static str,
+        applied: bool,
+        field_mode: &F,
+        struct_mode: &S,
+    ) -> Self {
+        Mode {
+            name,
+            applied,
+            span: field_mode.as_span().or_else(|| struct_mode.as_span()),
+        }
+    }
+}
+
+/// Whether an exclusive mode is applied to the field.
+///
+/// A field which has its own exclusive mode doesn't inherit the exclusive modes of the structure.
+pub fn exclusive_mode<F, S>(field_mode: &F, struct_mode: &S, has_field_mode: bool) -> bool
+where
+    F: AsBool,
+    S: AsBool,
+{
+    field_mode
+        .as_bool()
+        .unwrap_or_else(|| !has_field_mode && struct_mode.bool())
+}
+
+/// Emit an error if more than one of the exclusive modes are applied, the error points at both of them.
+///
+/// The getter or setter is still generated with the first applied mode.
+pub fn check_exclusive_modes(attr: &str, modes: &[Mode], default_span: Span) {
+    let applied = modes.iter().filter(|mode| mode.applied).collect::<Vec<_>>();
+
+    if let [first, second, ..] = applied.as_slice() {
+        let help = "a mode of the field replaces the modes of the structure";
+
+        emit_error!(
+            first.span.unwrap_or(default_span),
+            "#[{}({})] conflicts with #[{}({})]", attr, first.name, attr, second.name;
+            help = help
+        );
+        emit_error!(
+            second.span.unwrap_or(default_span),
+            "#[{}({})] conflicts with #[{}({})]", attr, second.name, attr, first.name;
+            help = help
+        );
+    }
+}
+
+/// The inline attributes of the generated methods like `inline(never)`, `None` keeps the default `#[inline(always)]`.
+pub fn inline(arg: &Option<NameArgs<Option<Toggle<Ident>>>>, attr: &str) -> Option<Vec<Attribute>> {
+    let arg = arg.as_ref()?;
+
+    match arg.args {
+        None => Some(vec![parse_quote! { #[inline] }]),
+        Some(Toggle::Bool(ref value)) if value.value => Some(vec![parse_quote! { #[inline] }]),
+        Some(Toggle::Bool(_)) => Some(vec![]),
+        Some(Toggle::Args(ref hint)) if hint == "always" || hint == "never" => {
+            Some(vec![parse_quote! { #[inline(#hint)] }])
+        }
+        Some(Toggle::Args(ref hint)) => {
+            emit_error!(
+                hint,
+                "unknown policy `{}` of #[{}(inline(..))]", hint, attr;
+                help = "supported policies are `always`, `never` or a boolean"
+            );
+
+            None
+        }
+    }
+}
+
+/// The span of the argument if the mode of the field can't be applied, then the mode is ignored.
+pub fn misapplied_mode<A>(field_mode: &A, applied: bool) -> Option<Span>
+where
+    A: AsBool + AsSpan,
+{
+    if field_mode.bool() && !applied {
+        field_mode.as_span()
+    } else {
+        None
+    }
+}
+
+/// The arguments of a mode which can be turned off with a boolean, like `opt(false)` or `opt(has)`.
+#[derive(Clone, Debug)]
+pub enum Toggle<T> {
+    Bool(LitBool),
+    Args(T),
+}
+
+impl<T> Toggle<T> {
+    pub fn args(&self) -> Option<&T> {
+        match self {
+            Toggle::Bool(_) => None,
+            Toggle::Args(args) => Some(args),
+        }
+    }
+}
+
+impl<T: Parse> Parse for Toggle<T> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitBool) {
+            input.parse().map(Toggle::Bool)
+        } else {
+            input.parse().map(Toggle::Args)
+        }
+    }
+}
+
+/// The methods forwarded to a nested field, like `flatten(port: u16 copy, host: str)`.
+///
+/// The `prefix = "..."` and `suffix = "..."` entries describe the naming of the methods in the nested type.
+#[derive(Clone, Debug, Default)]
+pub struct Flatten {
+    pub methods: Vec<FlattenMethod>,
+    pub prefix: Option<LitStr>,
+    pub suffix: Option<LitStr>,
+}
+
+impl Flatten {
+    /// The name of a method in the nested type, like `set_port` for `port` with the `set_` default prefix.
+    pub fn inner_name(&self, default_prefix: &str, name: &Ident) -> Ident {
+        format_ident!(
+            "{}{}{}",
+            self.prefix
+                .as_ref()
+                .map_or_else(|| default_prefix.to_string(), |s| format!("{}_", s.value())),
+            name,
+            self.suffix
+                .as_ref()
+                .map_or_else(String::new, |s| format!("_{}", s.value()))
+        )
+    }
+}
+
+impl Parse for Flatten {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut flatten = Flatten::default();
+
+        while !input.is_empty() {
+            if input.peek(Ident) && input.peek2(Token![=]) {
+                let name = input.parse::<Ident>()?;
+                input.parse::<Token![=]>()?;
+
+                if name == "prefix" {
+                    flatten.prefix = Some(input.parse()?);
+                } else if name == "suffix" {
+                    flatten.suffix = Some(input.parse()?);
+                } else {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("unknown argument `{}`, expected `prefix` or `suffix`", name),
+                    ));
+                }
+            } else {
+                flatten.methods.push(input.parse()?);
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(flatten)
+    }
+}
+
+/// A forwarded method with its type and modes, like `port: u16 copy`.
+#[derive(Clone, Debug)]
+pub struct FlattenMethod {
+    pub name: Ident,
+    pub ty: Type,
+    pub modes: Modes,
+}
+
+impl Parse for FlattenMethod {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        let mut modes = Modes::default();
+
+        while input.peek(Ident::peek_any) {
+            modes.0.push(input.call(Ident::parse_any)?);
+        }
+
+        Ok(FlattenMethod { name, ty, modes })
+    }
+}
+
+/// The modes of a declared method, like `copy` of `port: u16 copy`.
+#[derive(Clone, Debug, Default)]
+pub struct Modes(pub Vec<Ident>);
+
+impl Modes {
+    pub fn has(&self, mode: &str) -> bool {
+        self.0.iter().any(|ident| ident == mode)
+    }
+
+    /// Emit an error for each mode which is not in the supported list, returns whether all the modes are supported.
+    pub fn check(&self, attr: &str, arg: &str, supported: &[&str]) -> bool {
+        let mut supported_all = true;
+
+        for mode in self
+            .0
+            .iter()
+            .filter(|ident| !supported.iter().any(|name| *ident == name))
+        {
+            emit_error!(
+                mode.span(),
+                "unknown mode `{}` of #[{}({}(..))]", mode, attr, arg;
+                help = "supported modes are {}", supported.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>().join(", ")
+            );
+
+            supported_all = false;
+        }
+
+        supported_all
+    }
+}
+
+/// Extract the arguments of a mode from the field or the structure.
+pub fn toggle_args<'a, T>(
+    field_toggle: &'a Option<NameArgs<Option<Toggle<T>>>>,
+    struct_toggle: &'a Option<NameArgs<Option<Toggle<T>>>>,
+) -> impl Iterator<Item = &'a T> {
+    [field_toggle, struct_toggle]
+        .into_iter()
+        .flat_map(|toggle| toggle.as_ref())
+        .flat_map(|arg| arg.args.as_ref())
+        .flat_map(|toggle| toggle.args())
+}
+
+pub fn merge_bool<L, R>(lhs: &L, rhs: &R) -> Option<bool>
+where
+    L: AsBool,
+    R: AsBool,
+{
+    lhs.as_bool().or(rhs.as_bool())
+}
+
+pub fn merge_name_args<T: Clone>(
+    lhs: &mut Option<NameArgs<Vec<T>>>,
+    rhs: Option<NameArgs<Vec<T>>>,
+) {
+    if let Some(lhs) = lhs {
+        if let Some(rhs) = rhs {
+            lhs.args.extend(rhs.args);
+        }
+    } else {
+        *lhs = rhs
+    }
+}
+
+pub fn merge_flag(lhs: &mut Flag, rhs: Flag) {
+    if rhs.span.is_some() {
+        lhs.span = rhs.span
+    }
+}
+
+const WELL_KNOWN_ATTRS: &[&str] = &[
+    "allow",
+    "cfg",
+    "cfg_attr",
+    "deny",
+    "deprecated",
+    "doc",
+    "forbid",
+    "must_use",
+    "warn",
+];
+
+pub fn extract<T, I>(
+    attrs: I,
+    name: &str,
+    allowed_attrs: Option<Vec<String>>,
+) -> (T, Option<Span>, Vec<Attribute>)
+where
+    I: IntoIterator<Item = Attribute>,
+    T: Default + Merge + Parse + ArgNames,
+{
+    let (args, attrs): (Vec<_>, Vec<_>) = attrs
+        .into_iter()
+        .partition(|attr| attr.path().is_ident(name));
+
+    let (args, span) = parse_args(args, name);
+    let attrs = extract_attrs(attrs, allowed_attrs);
+
+    (args, span, attrs)
+}
+
+/// The arguments of an attribute like `#[get(..)]`, which are parsed by [`extract_struct_args`](crate::extract_struct_args)
+/// and [`extract_field_args`](crate::extract_field_args).
+pub trait AttrArgs: Default + Merge + Parse + ArgNames {
+    /// The name of the attribute, like `get`.
+    const ATTR: &'static str;
+}
+
+impl AttrArgs for crate::getter::StructArgs {
+    const ATTR: &'static str = "get";
+}
+
+impl AttrArgs for crate::getter::FieldArgs {
+    const ATTR: &'static str = "get";
+}
+
+impl AttrArgs for crate::setter::StructArgs {
+    const ATTR: &'static str = "set";
+}
+
+impl AttrArgs for crate::setter::FieldArgs {
+    const ATTR: &'static str = "set";
+}
+
+impl AttrArgs for crate::builder::StructArgs {
+    const ATTR: &'static str = "builder";
+}
+
+/// Extract the arguments of the structure, which are layered over the defaults of the options.
+pub fn extract_struct<T, I>(attrs: I, name: &str, options: &Options) -> (T, Option<Span>)
+where
+    I: IntoIterator<Item = Attribute>,
+    T: Default + Merge + Parse + ArgNames,
+{
+    let (mut args, span, _) = extract::<T, _>(attrs, name, None);
+
+    for defaults in options.defaults(name) {
+        args.merge(defaults);
+    }
+
+    (args, span)
+}
+
+fn parse_args<T>(args: Vec<Attribute>, name: &str) -> (T, Option<Span>)
+where
+    T: Default + Merge + Parse + ArgNames,
+{
+    args.into_iter()
+        .map(|attr| match attr.parse_args::<T>() {
+            Ok(args) => (args, attr.span()),
+            Err(err) => (parse_each_arg(&attr, name, err), attr.span()),
+        })
+        .fold(
+            (T::default(), None::<Span>),
+            |(mut cur, span), (new, attr_span)| {
+                cur.merge(new);
+                (
+                    cur,
+                    if let Some(span) = span {
+                        span.join(attr_span)
+                    } else {
+                        Some(attr_span)
+                    },
+                )
+            },
+        )
+}
+
+fn parse_each_arg<T>(attr: &Attribute, name: &str, err: syn::Error) -> T
+where
+    T: Default + Merge + Parse + ArgNames,
+{
+    // the invalid arguments are ignored, so the other arguments still take effect
+    let mut args = T::default();
+    let mut reported = check_arg_names::<T>(attr, name);
+
+    for tokens in split_args(attr) {
+        let is_known = match tokens.clone().into_iter().next() {
+            Some(TokenTree::Ident(ident)) => T::NAMES.contains(&ident.to_string().as_str()),
+            _ => true,
+        };
+
+        match syn::parse2::<T>(tokens) {
+            Ok(arg) => args.merge(arg),
+            Err(err) if is_known => {
+                emit_error!(err.span(), "invalid #[{}(..)] attribute, {}", name, err);
+                reported = true;
+            }
+            Err(_) => {}
+        }
+    }
+
+    if !reported {
+        emit_error!(err.span(), "invalid #[{}(..)] attribute, {}", name, err);
+    }
+
+    args
+}
+
+/// The names of the arguments accepted by an attribute, which explain the unknown arguments.
+pub trait ArgNames {
+    const NAMES: &'static [&'static str];
+
+    /// The level which accepts the other arguments, like the `rename` of the fields for the structure.
+    const OTHER_LEVEL: Option<(&'static str, &'static [&'static str])> = None;
+}
+
+/// The names of the arguments of each attribute, to detect an argument of the other derive.
+const ATTR_NAMES: &[(&str, &[&[&str]])] = &[
+    (
+        "get",
+        &[
+            crate::getter::StructArgs::NAMES,
+            crate::getter::FieldArgs::NAMES,
+        ],
+    ),
+    (
+        "set",
+        &[
+            crate::setter::StructArgs::NAMES,
+            crate::setter::FieldArgs::NAMES,
+        ],
+    ),
+    ("builder", &[crate::builder::StructArgs::NAMES]),
+];
+
+/// Emit an error for each unknown argument of the attribute, returns whether any of them is found.
+///
+/// The argument may belong to the other level or the other derive, or be a typo of a known argument.
+fn check_arg_names<T: ArgNames>(attr: &Attribute, name: &str) -> bool {
+    let mut found = false;
+
+    for arg in arg_names(attr) {
+        let arg_name = arg.to_string();
+
+        if T::NAMES.contains(&arg_name.as_str()) {
+            continue;
+        }
+
+        found = true;
+
+        if let Some((level, _)) =
+            T::OTHER_LEVEL.filter(|(_, names)| names.contains(&arg_name.as_str()))
+        {
+            emit_error!(
+                arg,
+                "#[{}({})] can only be applied to {}",
+                name,
+                arg_name,
+                level
+            );
+        } else if let Some((other, _)) = ATTR_NAMES.iter().find(|(other, names)| {
+            *other != name && names.iter().any(|names| names.contains(&arg_name.as_str()))
+        }) {
+            emit_error!(
+                arg,
+                "`{}` is a #[{}(..)] option, which isn't supported by #[{}(..)]", arg_name, other, name;
+                help = "use #[{}({})] instead", other, arg_name
+            );
+        } else if let Some(similar) = similar_name(&arg_name, T::NAMES) {
+            emit_error!(
+                arg,
+                "unknown argument `{}` of #[{}(..)]", arg_name, name;
+                help = "did you mean `{}`?", similar
+            );
+        } else {
+            emit_error!(arg, "unknown argument `{}` of #[{}(..)]", arg_name, name);
+        }
+    }
+
+    found
+}
+
+/// The names of the arguments in the attribute, like `copy` and `prefix` of `#[get(copy, prefix = "get_")]`.
+fn arg_names(attr: &Attribute) -> Vec<Ident> {
+    split_args(attr)
+        .into_iter()
+        .filter_map(|tokens| match tokens.into_iter().next() {
+            Some(TokenTree::Ident(ident)) => Some(ident),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The tokens of each argument in the attribute, like `copy` and `prefix = "get_"` of `#[get(copy, prefix = "get_")]`.
+fn split_args(attr: &Attribute) -> Vec<TokenStream> {
+    let tokens = match attr.meta {
+        Meta::List(ref list) => list.tokens.clone(),
+        _ => return vec![],
+    };
+    let mut args = vec![];
+    let mut arg = vec![];
+    let mut depth = 0;
+
+    // the commas of the generic arguments like `impl_trait = Foo<A, B>` don't separate the arguments
+    for token in tokens {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == '<' => depth += 1,
+            TokenTree::Punct(ref punct) if punct.as_char() == '>' && depth > 0 => depth -= 1,
+            TokenTree::Punct(ref punct) if punct.as_char() == ',' && depth == 0 => {
+                args.push(arg.drain(..).collect());
+                continue;
+            }
+            _ => {}
+        }
+
+        arg.push(token);
+    }
+
+    if !arg.is_empty() {
+        args.push(arg.into_iter().collect());
+    }
+
+    args
+}
+
+/// The most similar name within the edit distance, which is a suggestion for a typo.
+fn similar_name<'a>(name: &str, names: &[&'a str]) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    names
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The edit distance between two names, which counts a transposition like `slcie` as one edit.
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let lhs = lhs.chars().collect::<Vec<_>>();
+    let rhs = rhs.chars().collect::<Vec<_>>();
+    let mut distances = vec![vec![0; rhs.len() + 1]; lhs.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=lhs.len() {
+        for j in 1..=rhs.len() {
+            let cost = usize::from(lhs[i - 1] != rhs[j - 1]);
+
+            distances[i][j] = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && lhs[i - 1] == rhs[j - 2] && lhs[i - 2] == rhs[j - 1] {
+                distances[i][j] = distances[i][j].min(distances[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    distances[lhs.len()][rhs.len()]
+}
+
+fn extract_attrs(attrs: Vec<Attribute>, allowed_attrs: Option<Vec<String>>) -> Vec<Attribute> {
+    attrs
+        .into_iter()
+        .filter(|attr| {
+            let ident = &attr.path().segments.first().unwrap().ident;
+
+            attr.style == AttrStyle::Outer
+                && (WELL_KNOWN_ATTRS.iter().any(|name| ident == name)
+                    || allowed_attrs
+                        .as_ref()
+                        .map_or(false, |attrs| attrs.iter().any(|name| ident == name)))
+        })
+        .collect::<Vec<_>>()
+}
+
+/// The path of the `getset2` crate given with `crate = ...`, which provides the runtime items of the generated code.
+pub fn crate_path(krate: &Option<NameValue<Path>>) -> Path {
+    krate
+        .as_ref()
+        .map_or_else(|| parse_quote!(::getset2), |arg| arg.value.clone())
+}
+
+/// The crate of the allocated types like `Box` and `Vec`, which is `alloc` for the `no_std` crates.
+pub fn alloc_crate(no_std: bool) -> Ident {
+    if no_std {
+        format_ident!("alloc")
+    } else {
+        format_ident!("std")
+    }
+}
+
+pub fn vis(
+    field_vis: &Option<NameArgs<Option<Restricted>>>,
+    struct_vis: &Option<NameArgs<Option<Restricted>>>,
+    default_vis: &Visibility,
+) -> Visibility {
+    field_vis
+        .as_ref()
+        .or(struct_vis.as_ref())
+        .as_visibility()
+        .unwrap_or_else(|| default_vis.clone())
+}
+
+pub fn constness(
+    field_constness: &Option<NameArgs<Option<LitBool>>>,
+    struct_constness: &Flag,
+) -> Option<Token![const]> {
+    if merge_bool(field_constness, struct_constness).unwrap_or_default() {
+        Some(parse_quote! { const })
+    } else {
+        None
+    }
+}
+
+pub fn prefix(
+    field_prefix: &Option<NameValue<LitStr>>,
+    struct_prefix: &Option<NameValue<LitStr>>,
+) -> Option<String> {
+    field_prefix
+        .as_ref()
+        .or(struct_prefix.as_ref())
+        .map(|s| format!("{}_", s.value.value()))
+}
+
+pub fn suffix(
+    field_suffix: &Option<NameValue<LitStr>>,
+    struct_suffix: &Option<NameValue<LitStr>>,
+) -> Option<String> {
+    field_suffix
+        .as_ref()
+        .or(struct_suffix.as_ref())
+        .map(|s| format!("_{}", s.value.value()))
+}
+
+pub fn name(
+    field_rename: &Option<NameArgs<Ident>>,
+    field_ident: &Option<Ident>,
+    field_idx: usize,
+) -> Ident {
+    field_rename
+        .as_ref()
+        .map(|arg| arg.args.clone())
+        .unwrap_or_else(|| match field_ident {
+            Some(ref name) => name.clone(),
+            None => format_ident!("arg{}", field_idx),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+    use syn::{parse::Parse, Ident};
+
+    use super::ArgNames;
+
+    // the names are listed by hand, so each of them should be a parameter of the arguments
+    fn check_names<T: Parse + ArgNames>() {
+        for name in T::NAMES {
+            let ident = Ident::new(name, Span::call_site());
+
+            if let Err(err) = syn::parse2::<T>(quote::quote! { #ident }) {
+                assert!(
+                    !err.to_string().starts_with("cannot find parameter"),
+                    "`{}` isn't an argument: {}",
+                    name,
+                    err
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn arg_names() {
+        check_names::<crate::getter::StructArgs>();
+        check_names::<crate::getter::FieldArgs>();
+        check_names::<crate::getter::VariantArgs>();
+        check_names::<crate::setter::StructArgs>();
+        check_names::<crate::setter::FieldArgs>();
+        check_names::<crate::builder::StructArgs>();
+    }
+}
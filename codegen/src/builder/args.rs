@@ -0,0 +1,26 @@
+use merge::Merge;
+use structmeta::{Flag, NameArgs, NameValue, StructMeta};
+use syn::{Ident, LitStr};
+
+use crate::{
+    args::{merge_flag, ArgNames},
+    vis::Restricted,
+};
+
+#[derive(Clone, Debug, Default, Merge, StructMeta)]
+pub struct StructArgs {
+    #[struct_meta(name = "pub")]
+    pub vis: Option<NameArgs<Option<Restricted>>>,
+    pub rename: Option<NameArgs<Ident>>,
+    pub prefix: Option<NameValue<LitStr>>,
+    pub suffix: Option<NameValue<LitStr>>,
+    #[merge(strategy = merge_flag)]
+    pub typestate: Flag,
+    #[merge(strategy = merge_flag)]
+    pub no_std: Flag,
+}
+
+impl ArgNames for StructArgs {
+    const NAMES: &'static [&'static str] =
+        &["pub", "rename", "prefix", "suffix", "typestate", "no_std"];
+}
@@ -0,0 +1,479 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::abort;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parse_quote, spanned::Spanned, Data, DataStruct, DeriveInput, Expr, Fields, FieldsNamed,
+    FieldsUnnamed, GenericArgument, GenericParam, Generics, Ident, ItemFn, Member, PathArguments,
+    ReturnType, Type, TypePath,
+};
+
+use crate::{
+    args,
+    field::Field,
+    setter::{self, owned, Context},
+    ty::TypeExt,
+    Options,
+};
+
+use super::StructArgs;
+
+pub fn expand(input: DeriveInput, options: &Options) -> TokenStream {
+    let DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics,
+        data,
+    } = input.clone();
+
+    let (builder_args, _) =
+        args::extract_struct::<StructArgs, _>(attrs.clone(), "builder", options);
+    let (mut setter_args, setter_args_span) =
+        args::extract_struct::<setter::StructArgs, _>(attrs, "set", options);
+
+    // the visibility and naming of the setters on the struct don't apply to the builder
+    setter_args.vis = builder_args.vis.clone();
+    setter_args.prefix = builder_args.prefix.clone();
+    setter_args.suffix = builder_args.suffix.clone();
+
+    let (named, fields) = match data {
+        Data::Struct(DataStruct { fields, .. }) => match fields {
+            Fields::Named(FieldsNamed { named, .. }) => (true, named.into_iter().collect()),
+            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
+                (false, unnamed.into_iter().collect())
+            }
+            Fields::Unit => (true, vec![]),
+        },
+        _ => abort!(input, "#[derive(Builder)] can only be applied to structure"),
+    };
+
+    let builder_vis = args::vis(&builder_args.vis, &None, &vis);
+    let builder = Builder {
+        ident: ident.clone(),
+        builder_ident: builder_args
+            .rename
+            .as_ref()
+            .map(|arg| arg.args.clone())
+            .unwrap_or_else(|| format_ident!("{}Builder", ident)),
+        generics,
+        named,
+        typestate: builder_args.typestate.span.is_some(),
+        no_std: builder_args.no_std.value(),
+        fields: fields
+            .into_iter()
+            .enumerate()
+            .map(|(field_idx, field)| {
+                Context::with_default_prefix(
+                    &setter_args,
+                    setter_args_span,
+                    &builder_vis,
+                    Field::slot(
+                        syn::Field {
+                            vis: builder_vis.clone(),
+                            ..field
+                        },
+                        field_idx,
+                    ),
+                    "",
+                )
+            })
+            .collect(),
+        vis: builder_vis,
+        span: input.span(),
+    };
+
+    builder.into_token_stream()
+}
+
+struct Builder<'a> {
+    ident: Ident,
+    builder_ident: Ident,
+    vis: syn::Visibility,
+    generics: Generics,
+    named: bool,
+    typestate: bool,
+    no_std: bool,
+    fields: Vec<Context<'a>>,
+    span: Span,
+}
+
+impl Builder<'_> {
+    fn error_ident(&self) -> Ident {
+        format_ident!("{}Error", self.builder_ident)
+    }
+
+    /// The field must be set before building the structure.
+    fn is_required(ctx: &Context) -> bool {
+        !ctx.field.args.skip
+            && ctx.field.args.default.is_none()
+            && !ctx.is_extend()
+            && ctx.field.ty.option_inner_ty().is_none()
+    }
+
+    fn required_fields(&self) -> impl Iterator<Item = &Context<'_>> {
+        self.fields.iter().filter(|ctx| Self::is_required(ctx))
+    }
+
+    /// The name of the const generic parameter which tracks the state of a required field.
+    fn state_param(ctx: &Context) -> Ident {
+        let name = match ctx.field.member() {
+            Member::Named(ident) => ident.to_string().trim_start_matches("r#").to_uppercase(),
+            Member::Unnamed(idx) => idx.index.to_string(),
+        };
+
+        format_ident!("__{}", name)
+    }
+
+    /// The type of the builder, with the states of the required fields.
+    fn builder_ty<I>(&self, states: I) -> Type
+    where
+        I: IntoIterator,
+        I::Item: ToTokens,
+    {
+        let builder_ident = &self.builder_ident;
+        let params = self
+            .generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+                GenericParam::Type(param) => param.ident.to_token_stream(),
+                GenericParam::Const(param) => param.ident.to_token_stream(),
+            })
+            .chain(states.into_iter().map(|state| state.to_token_stream()))
+            .collect::<Vec<_>>();
+
+        if params.is_empty() {
+            parse_quote! { #builder_ident }
+        } else {
+            parse_quote! { #builder_ident < #( #params ),* > }
+        }
+    }
+
+    fn initial_ty(&self) -> Type {
+        self.builder_ty(self.states().map(|_| quote! { false }))
+    }
+
+    fn final_ty(&self) -> Type {
+        self.builder_ty(self.states().map(|_| quote! { true }))
+    }
+
+    /// The required fields whose states are tracked by the typestate builder.
+    fn states(&self) -> impl Iterator<Item = &Context<'_>> {
+        self.required_fields().filter(move |_| self.typestate)
+    }
+
+    /// The generics of the builder, with the const parameters which track the states of the required fields.
+    fn builder_generics(&self) -> Generics {
+        let mut generics = self.generics.clone();
+
+        for ctx in self.states() {
+            let param = Self::state_param(ctx);
+
+            generics.params.push(parse_quote! { const #param: bool });
+        }
+
+        generics
+    }
+
+    fn declaration(&self) -> TokenStream {
+        let vis = &self.vis;
+        let builder_ident = &self.builder_ident;
+        let generics = self.builder_generics();
+        let (_, _, where_clause) = self.generics.split_for_impl();
+        let tys = self.fields.iter().map(|ctx| &ctx.field.ty);
+        let doc = format!("A builder for [`{}`].", self.ident);
+
+        if self.named {
+            let names = self.fields.iter().map(|ctx| ctx.field.member());
+
+            quote! {
+                #[doc = #doc]
+                #vis struct #builder_ident #generics #where_clause {
+                    #( #names: ::core::option::Option<#tys>, )*
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                #vis struct #builder_ident #generics ( #( ::core::option::Option<#tys> ),* ) #where_clause;
+            }
+        }
+    }
+
+    fn constructor(&self) -> TokenStream {
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let initial_ty = self.initial_ty();
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let members = self.fields.iter().map(|ctx| ctx.field.member());
+        let doc = format!("Create a builder for [`{}`].", ident);
+
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc = #doc]
+                #[inline(always)]
+                #vis fn builder() -> #initial_ty {
+                    ::core::default::Default::default()
+                }
+            }
+
+            impl #impl_generics ::core::default::Default for #initial_ty #where_clause {
+                fn default() -> Self {
+                    #builder_ident {
+                        #( #members: ::core::option::Option::None, )*
+                    }
+                }
+            }
+        }
+    }
+
+    fn setters(&self) -> TokenStream {
+        let generics = self.builder_generics();
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (_, _, where_clause) = self.generics.split_for_impl();
+        let self_ty = self.builder_ty(self.states().map(Self::state_param));
+        let setters = self.fields.iter().flat_map(|ctx| {
+            ctx.setters()
+                .into_iter()
+                .filter(move |setter| !self.typestate || owned::is_chained(setter))
+                .map(move |setter| {
+                    if !self.typestate {
+                        setter
+                    } else if Self::is_required(ctx) {
+                        self.transit(ctx, owned::setter(setter))
+                    } else {
+                        owned::setter(setter)
+                    }
+                })
+        });
+
+        quote! {
+            impl #impl_generics #self_ty #where_clause {
+                #( #setters )*
+            }
+        }
+    }
+
+    /// Move the builder to the state where the required field has been set.
+    fn transit(&self, ctx: &Context, mut setter: ItemFn) -> ItemFn {
+        let builder_ident = &self.builder_ident;
+        let param = Self::state_param(ctx);
+        let target = self.builder_ty(self.required_fields().map(|field| {
+            let state = Self::state_param(field);
+
+            if state == param {
+                quote! { true }
+            } else {
+                state.into_token_stream()
+            }
+        }));
+        let members = self
+            .fields
+            .iter()
+            .map(|ctx| ctx.field.member())
+            .collect::<Vec<_>>();
+        let block = &setter.block;
+
+        setter.block = match setter.sig.output {
+            ReturnType::Type(_, ref mut ty) if ty.is_ty("Self") => {
+                **ty = target;
+
+                parse_quote! {{
+                    let this = #block;
+
+                    #builder_ident { #( #members: this.#members, )* }
+                }}
+            }
+            ReturnType::Type(_, ref mut ty) => {
+                replace_self(ty, &target);
+
+                parse_quote! {{
+                    let this = #block?;
+
+                    ::core::result::Result::Ok(#builder_ident { #( #members: this.#members, )* })
+                }}
+            }
+            ReturnType::Default => setter.block,
+        };
+
+        setter
+    }
+
+    fn build(&self) -> TokenStream {
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let final_ty = self.final_ty();
+        let members = self
+            .fields
+            .iter()
+            .map(|ctx| ctx.field.member())
+            .collect::<Vec<_>>();
+        let doc = format!("Build a [`{}`] with the values of the builder.", ident);
+
+        if self.typestate {
+            let values = self.fields.iter().map(|ctx| {
+                let member = ctx.field.member();
+
+                Self::value(ctx, parse_quote! { self.#member })
+            });
+
+            return quote! {
+                impl #impl_generics #final_ty #where_clause {
+                    #[doc = #doc]
+                    #[inline(always)]
+                    #vis fn build(self) -> #ident #ty_generics {
+                        #ident { #( #members: #values, )* }
+                    }
+                }
+            };
+        }
+
+        let error_ident = self.error_ident();
+        let required = self
+            .required_fields()
+            .map(|ctx| ctx.field.member())
+            .collect::<Vec<_>>();
+        let names = self.required_fields().map(|ctx| ctx.field.name_str());
+        let alloc_crate = args::alloc_crate(self.no_std);
+        let check = if required.is_empty() {
+            None
+        } else {
+            Some(quote! {
+                let mut missing = ::#alloc_crate::vec::Vec::new();
+
+                #(
+                    if self.#required.is_none() {
+                        missing.push(#names);
+                    }
+                )*
+
+                if !missing.is_empty() {
+                    return ::core::result::Result::Err(#error_ident { missing });
+                }
+            })
+        };
+        let values = self.fields.iter().map(|ctx| {
+            let member = ctx.field.member();
+
+            Self::value(ctx, parse_quote! { self.#member.take() })
+        });
+
+        quote! {
+            impl #impl_generics #final_ty #where_clause {
+                #[doc = #doc]
+                ///
+                /// The values are moved out of the builder, or an error is returned if some of the required fields are missing.
+                #vis fn build(&mut self) -> ::core::result::Result<#ident #ty_generics, #error_ident> {
+                    #check
+
+                    ::core::result::Result::Ok(#ident { #( #members: #values, )* })
+                }
+            }
+        }
+    }
+
+    fn value(ctx: &Context, slot: Expr) -> Expr {
+        if Self::is_required(ctx) {
+            parse_quote! { #slot.unwrap() }
+        } else if let Some(value) = ctx.field.args.default_value() {
+            parse_quote! { #slot.unwrap_or_else(|| #value) }
+        } else {
+            parse_quote! { #slot.unwrap_or_default() }
+        }
+    }
+
+    fn error(&self) -> Option<TokenStream> {
+        if self.typestate {
+            return None;
+        }
+
+        let vis = &self.vis;
+        let error_ident = self.error_ident();
+        let alloc_crate = args::alloc_crate(self.no_std);
+        // `core::error::Error` isn't stable in the supported Rust versions, so only `Display` is implemented
+        let error_impl = if self.no_std {
+            None
+        } else {
+            Some(quote! {
+                impl ::std::error::Error for #error_ident {}
+            })
+        };
+        let doc = format!(
+            "An error returned by [`{}::build`] when some of the required fields are missing.",
+            self.builder_ident
+        );
+
+        Some(quote! {
+            #[doc = #doc]
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            #vis struct #error_ident {
+                missing: ::#alloc_crate::vec::Vec<&'static str>,
+            }
+
+            impl #error_ident {
+                /// The names of the missing fields.
+                #[inline(always)]
+                #vis fn missing_fields(&self) -> &[&'static str] {
+                    &self.missing
+                }
+            }
+
+            impl ::core::fmt::Display for #error_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str("missing required field(s): ")?;
+
+                    for (idx, name) in self.missing.iter().enumerate() {
+                        if idx > 0 {
+                            f.write_str(", ")?;
+                        }
+
+                        ::core::write!(f, "`{}`", name)?;
+                    }
+
+                    ::core::result::Result::Ok(())
+                }
+            }
+
+            #error_impl
+        })
+    }
+}
+
+impl ToTokens for Builder<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let declaration = self.declaration();
+        let constructor = self.constructor();
+        let setters = self.setters();
+        let build = self.build();
+        let error = self.error();
+
+        quote_spanned! { self.span =>
+            #declaration
+            #constructor
+            #setters
+            #build
+            #error
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Replace the `Self` type with the given type, like `Result<Self, E>`.
+fn replace_self(ty: &mut Type, target: &Type) {
+    if ty.is_ty("Self") {
+        *ty = target.clone();
+    } else if let Type::Path(TypePath { ref mut path, .. }) = ty {
+        for segment in path.segments.iter_mut() {
+            if let PathArguments::AngleBracketed(ref mut args) = segment.arguments {
+                for arg in args.args.iter_mut() {
+                    if let GenericArgument::Type(ref mut ty) = arg {
+                        replace_self(ty, target);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod args;
+mod expand;
+
+pub use self::args::StructArgs;
+pub use self::expand::expand;
@@ -0,0 +1,326 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::{abort, emit_error};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote_spanned,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Data, DataStruct, DeriveInput, Expr, ExprLit, Field, Ident, Lit, Meta, Token,
+    Visibility,
+};
+
+use crate::{getter, methods::Target, setter, Options};
+
+/// The accessors of the original `getset` crate, each of them has an attribute like `#[get_copy = "pub"]`,
+/// which is also an argument of `#[getset(..)]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Get,
+    GetCopy,
+    GetMut,
+    Set,
+}
+
+impl Kind {
+    const ALL: [Kind; 4] = [Kind::Get, Kind::GetCopy, Kind::GetMut, Kind::Set];
+
+    pub const GETTERS: &'static [Kind] = &[Kind::Get, Kind::GetCopy, Kind::GetMut];
+    pub const SETTERS: &'static [Kind] = &[Kind::Set];
+
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Get => "get",
+            Kind::GetCopy => "get_copy",
+            Kind::GetMut => "get_mut",
+            Kind::Set => "set",
+        }
+    }
+
+    fn derive(self) -> &'static str {
+        match self {
+            Kind::Get => "Getters",
+            Kind::GetCopy => "CopyGetters",
+            Kind::GetMut => "MutGetters",
+            Kind::Set => "Setters",
+        }
+    }
+
+    /// The attribute of getset2 which generates the accessor.
+    fn attr(self) -> &'static str {
+        match self {
+            Kind::Set => "set",
+            _ => "get",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Kind> {
+        Kind::ALL.iter().copied().find(|kind| kind.name() == name)
+    }
+}
+
+/// A setting like `get = "pub with_prefix"`, the visibility is omitted for a bare `get`.
+struct Setting {
+    kind: Kind,
+    vis: Option<Visibility>,
+    with_prefix: bool,
+    span: Span,
+}
+
+impl Setting {
+    /// The equivalent attribute of getset2, like `#[get(pub, copy, prefix = "get")]`.
+    fn to_attr(&self, default_vis: Option<TokenStream>) -> Attribute {
+        let vis = self
+            .vis
+            .as_ref()
+            .map(ToTokens::to_token_stream)
+            .or(default_vis);
+        let mode = match self.kind {
+            Kind::GetCopy => Some(quote! { copy }),
+            Kind::GetMut => Some(quote! { mut }),
+            Kind::Get | Kind::Set => None,
+        };
+        // `getset` only prepends `get_` to the immutable getters
+        let prefix = (self.with_prefix && matches!(self.kind, Kind::Get | Kind::GetCopy))
+            .then(|| quote! { prefix = "get" });
+        let args = vis.into_iter().chain(mode).chain(prefix);
+        let attr = Ident::new(self.kind.attr(), self.span);
+
+        parse_quote_spanned! { self.span =>
+            #[#attr( #( #args ),* )]
+        }
+    }
+}
+
+/// The value of a setting like `"pub(crate) with_prefix"`.
+struct Value {
+    vis: Visibility,
+    with_prefix: bool,
+}
+
+impl Parse for Value {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let with_prefix = match input.parse::<Option<Ident>>()? {
+            Some(ident) if ident == "with_prefix" => true,
+            Some(ident) => return Err(syn::Error::new(ident.span(), "expected `with_prefix`")),
+            None => false,
+        };
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected token"));
+        }
+
+        Ok(Value { vis, with_prefix })
+    }
+}
+
+/// The settings of the `getset` attributes on a structure or field.
+#[derive(Default)]
+struct Settings {
+    settings: Vec<Setting>,
+    skip: Option<Span>,
+}
+
+impl Settings {
+    /// Take the `getset` attributes out of the attributes, the invalid arguments are reported and ignored.
+    fn take(attrs: &mut Vec<Attribute>) -> Self {
+        let mut settings = Settings::default();
+
+        attrs.retain(|attr| {
+            if attr.path().is_ident("getset") {
+                settings.parse_getset(attr);
+            } else if let Some(kind) = attr
+                .path()
+                .get_ident()
+                .and_then(|ident| Kind::from_name(&ident.to_string()))
+            {
+                match attr.meta {
+                    // `#[get(..)]` and `#[set(..)]` are the attributes of getset2
+                    Meta::List(_) if matches!(kind, Kind::Get | Kind::Set) => return true,
+                    Meta::List(_) => emit_error!(
+                        attr,
+                        "expected #[{}] or #[{} = \"...\"]", kind.name(), kind.name();
+                        help = "use #[get(copy)] or #[get(mut)] for the arguments of getset2"
+                    ),
+                    _ => settings.parse_meta(kind, &attr.meta),
+                }
+            } else {
+                return true;
+            }
+
+            false
+        });
+
+        settings
+    }
+
+    /// Parse `#[getset(get = "pub", set, skip)]`.
+    fn parse_getset(&mut self, attr: &Attribute) {
+        let metas = match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+            Ok(metas) => metas,
+            Err(err) => {
+                emit_error!(err.span(), "invalid #[getset(..)] attribute, {}", err);
+
+                return;
+            }
+        };
+
+        for meta in metas {
+            let name = meta.path().get_ident().map(ToString::to_string);
+
+            match name.as_deref() {
+                Some("skip") if matches!(meta, Meta::Path(_)) => self.skip = Some(meta.span()),
+                Some(name) if Kind::from_name(name).is_some() && !matches!(meta, Meta::List(_)) => {
+                    self.parse_meta(Kind::from_name(name).unwrap(), &meta)
+                }
+                _ => emit_error!(
+                    meta,
+                    "unknown argument `{}` of #[getset(..)]", meta.path().to_token_stream();
+                    help = "supported arguments are `get`, `get_copy`, `get_mut`, `set` and `skip`"
+                ),
+            }
+        }
+    }
+
+    /// Parse `get` or `get = "pub(crate) with_prefix"`.
+    fn parse_meta(&mut self, kind: Kind, meta: &Meta) {
+        let value = match meta {
+            Meta::NameValue(meta) => match meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(ref lit),
+                    ..
+                }) => match lit.parse::<Value>() {
+                    Ok(value) => Some(value),
+                    Err(err) => {
+                        emit_error!(
+                            lit,
+                            "invalid visibility of `{}`, {}", kind.name(), err;
+                            help = "use a visibility like \"pub(crate)\", which may be followed by `with_prefix`"
+                        );
+
+                        return;
+                    }
+                },
+                ref value => {
+                    emit_error!(value, "expected a string like `{} = \"pub\"`", kind.name());
+
+                    return;
+                }
+            },
+            _ => None,
+        };
+
+        self.settings.push(Setting {
+            kind,
+            vis: value
+                .as_ref()
+                .map(|value| value.vis.clone())
+                .filter(|vis| !matches!(vis, Visibility::Inherited)),
+            with_prefix: value.map_or(false, |value| value.with_prefix),
+            span: meta.span(),
+        });
+    }
+
+    /// The setting of the accessor, the last one takes effect like `getset`.
+    fn get(&self, kind: Kind) -> Option<&Setting> {
+        self.settings
+            .iter()
+            .rev()
+            .find(|setting| setting.kind == kind)
+    }
+}
+
+/// Translate the `getset` attributes to the equivalent attributes of getset2 for the derives of getset2.
+pub fn translate(input: &mut DeriveInput, kinds: &[Kind]) {
+    translate_attrs(&mut input.attrs, kinds, None);
+
+    for field in fields_mut(&mut input.data) {
+        translate_attrs(&mut field.attrs, kinds, Some(kinds[0].attr()));
+    }
+}
+
+fn translate_attrs(attrs: &mut Vec<Attribute>, kinds: &[Kind], field_attr: Option<&str>) {
+    let settings = Settings::take(attrs);
+
+    for setting in settings
+        .settings
+        .iter()
+        .filter(|setting| kinds.contains(&setting.kind))
+    {
+        attrs.push(setting.to_attr(None));
+    }
+
+    if let Some(span) = settings.skip {
+        match field_attr {
+            Some(attr) => {
+                let attr = Ident::new(attr, span);
+
+                attrs.push(parse_quote_spanned! { span => #[#attr(skip)] });
+            }
+            None => emit_error!(span, "#[getset(skip)] can only be applied to field"),
+        }
+    }
+}
+
+fn fields_mut(data: &mut Data) -> Vec<&mut Field> {
+    match data {
+        Data::Struct(data) => data.fields.iter_mut().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter_mut()
+            .flat_map(|variant| variant.fields.iter_mut())
+            .collect(),
+        Data::Union(_) => vec![],
+    }
+}
+
+/// Expand an alias derive of `getset` like `#[derive(CopyGetters)]`,
+/// which only generates the accessors of the fields with the attribute of the field or the structure.
+pub fn expand(mut input: DeriveInput, kind: Kind, options: &Options) -> TokenStream {
+    let struct_settings = Settings::take(&mut input.attrs);
+
+    if let Some(span) = struct_settings.skip {
+        emit_error!(span, "#[getset(skip)] can only be applied to field");
+    }
+
+    let fields = match input.data {
+        Data::Struct(DataStruct { ref mut fields, .. }) => fields,
+        _ => abort!(
+            input,
+            "#[derive({})] can only be applied to structure",
+            kind.derive()
+        ),
+    };
+    let attr = Ident::new(kind.attr(), Span::call_site());
+
+    for field in fields.iter_mut() {
+        let settings = Settings::take(&mut field.attrs);
+        let setting = settings.get(kind).or_else(|| struct_settings.get(kind));
+
+        field.attrs.push(match (settings.skip, setting) {
+            // the accessors of `getset` are private by default
+            (None, Some(setting)) => setting.to_attr(Some(quote! { pub(self) })),
+            (skip, _) => {
+                let span = skip.unwrap_or_else(|| field.span());
+
+                parse_quote_spanned! { span => #[#attr(skip)] }
+            }
+        });
+    }
+
+    // `Getters` and `MutGetters` don't generate the same methods
+    let target = Target {
+        mutable: match kind {
+            Kind::Set => None,
+            Kind::GetMut => Some(true),
+            Kind::Get | Kind::GetCopy => Some(false),
+        },
+        ..Target::default()
+    };
+
+    match kind {
+        Kind::Set => setter::expand(input, options, &target),
+        _ => getter::expand(input, options, &target),
+    }
+}
@@ -0,0 +1,269 @@
+use std::{
+    cell::RefCell,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::Parse;
+use toml::{value::Table, Value};
+
+const CONFIG_FILE: &str = "getset2.toml";
+const METADATA_TABLE: &str = "[package.metadata.getset2]";
+const KEYS: &[&str] = &["builder", "get", "set"];
+
+thread_local! {
+    // the derives of a crate share the loaded defaults, so each error is reported by the first derive only
+    static CONFIGS: RefCell<Vec<(PathBuf, Option<Config>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The crate-wide defaults of the attribute like `get = 'pub(crate), prefix = "get"'`,
+/// which are read from `getset2.toml` or `[package.metadata.getset2]` of `Cargo.toml`.
+pub fn defaults<T: Parse>(name: &str) -> Option<T> {
+    let dir = manifest_dir()?;
+
+    CONFIGS.with(|configs| {
+        let mut configs = configs.borrow_mut();
+        let idx = match configs.iter().position(|(other, _)| *other == dir) {
+            Some(idx) => idx,
+            None => {
+                let config = match Config::load(&dir) {
+                    Ok(config) => config,
+                    Err(err) => {
+                        err.emit();
+
+                        None
+                    }
+                };
+
+                for err in config.iter().flat_map(Config::unknown_keys) {
+                    err.emit();
+                }
+
+                configs.push((dir, config));
+                configs.len() - 1
+            }
+        };
+        let config = configs[idx].1.as_mut()?;
+
+        match config.args(name) {
+            Ok(args) => args,
+            Err(err) => {
+                if !config.reported.iter().any(|key| key == name) {
+                    err.emit();
+                    config.reported.push(name.to_string());
+                }
+
+                None
+            }
+        }
+    })
+}
+
+/// Depend on `getset2.toml` with `include_bytes!`, so the crate is rebuilt when the defaults are changed.
+pub fn track() -> TokenStream {
+    match manifest_dir().map(|dir| dir.join(CONFIG_FILE)) {
+        Some(path) if path.is_file() => {
+            let path = path.to_string_lossy();
+
+            quote! {
+                const _: &[u8] = include_bytes!(#path);
+            }
+        }
+        _ => quote!(),
+    }
+}
+
+fn manifest_dir() -> Option<PathBuf> {
+    env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from)
+}
+
+#[derive(Debug)]
+struct Error {
+    msg: String,
+    help: Option<String>,
+}
+
+impl Error {
+    fn new(msg: String) -> Self {
+        Error { msg, help: None }
+    }
+
+    fn emit(&self) {
+        match self.help {
+            Some(ref help) => emit_error!(Span::call_site(), "{}", self.msg; help = help),
+            None => emit_error!(Span::call_site(), "{}", self.msg),
+        }
+    }
+}
+
+/// The table of defaults with the name of its source, and the keys whose errors have been reported.
+struct Config {
+    table: Table,
+    source: &'static str,
+    reported: Vec<String>,
+}
+
+impl Config {
+    /// Load the table of defaults, `getset2.toml` takes precedence over `Cargo.toml`.
+    fn load(dir: &Path) -> Result<Option<Self>, Error> {
+        let path = dir.join(CONFIG_FILE);
+        let (table, source) = if path.is_file() {
+            let table = read(&path)
+                .map_err(|err| Error::new(format!("invalid {}, {}", CONFIG_FILE, err)))?;
+
+            (table, CONFIG_FILE)
+        } else {
+            // the manifest has been checked by cargo, so it's ignored if it can't be read
+            let table = read(&dir.join("Cargo.toml")).ok().and_then(|manifest| {
+                manifest
+                    .get("package")?
+                    .get("metadata")?
+                    .get("getset2")
+                    .cloned()
+            });
+
+            match table {
+                Some(Value::Table(table)) => (table, METADATA_TABLE),
+                Some(value) => {
+                    return Err(Error::new(format!(
+                        "invalid {}, expected a table but found {}",
+                        METADATA_TABLE,
+                        value.type_str()
+                    )))
+                }
+                None => return Ok(None),
+            }
+        };
+
+        Ok(Some(Config {
+            table,
+            source,
+            reported: vec![],
+        }))
+    }
+
+    fn unknown_keys(&self) -> Vec<Error> {
+        self.table
+            .keys()
+            .filter(|key| !KEYS.contains(&key.as_str()))
+            .map(|key| Error {
+                msg: format!("unknown key `{}` of {}", key, self.source),
+                help: Some(format!(
+                    "supported keys are {}",
+                    KEYS.iter()
+                        .map(|key| format!("`{}`", key))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            })
+            .collect()
+    }
+
+    fn args<T: Parse>(&self, name: &str) -> Result<Option<T>, Error> {
+        match self.table.get(name) {
+            None => Ok(None),
+            Some(Value::String(args)) => syn::parse_str(args).map(Some).map_err(|err| Error {
+                msg: format!("invalid `{}` of {}, {}", name, self.source, err),
+                help: Some(format!("`{} = {:?}`", name, args)),
+            }),
+            Some(value) => Err(Error {
+                msg: format!(
+                    "invalid `{}` of {}, expected a string but found {}",
+                    name,
+                    self.source,
+                    value.type_str()
+                ),
+                help: Some(format!(
+                    "write the arguments like #[{}(..)] in a string, like `{} = 'pub(crate)'`",
+                    name, name
+                )),
+            }),
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<Table, String> {
+    let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+
+    toml::from_str(&content).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{getter, setter};
+
+    use super::Config;
+
+    fn load(fixture: &str) -> Config {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(fixture);
+
+        Config::load(&dir).unwrap().unwrap()
+    }
+
+    #[test]
+    fn config_file() {
+        let config = load("config_file");
+
+        assert_eq!(config.source, "getset2.toml");
+        assert!(config.unknown_keys().is_empty());
+        assert!(config.args::<getter::StructArgs>("get").unwrap().is_some());
+        assert!(config.args::<setter::StructArgs>("set").unwrap().is_none());
+    }
+
+    #[test]
+    fn config_metadata() {
+        let config = load("config_metadata");
+
+        assert_eq!(config.source, "[package.metadata.getset2]");
+        assert!(config.args::<getter::StructArgs>("get").unwrap().is_some());
+    }
+
+    #[test]
+    fn config_precedence() {
+        let config = load("config_precedence");
+
+        assert_eq!(config.source, "getset2.toml");
+        assert!(config.table["get"].as_str().unwrap().contains("from_file"));
+    }
+
+    #[test]
+    fn config_unknown_key() {
+        let errors = load("config_errors").unknown_keys();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].msg, "unknown key `gett` of getset2.toml");
+    }
+
+    #[test]
+    fn config_invalid_value() {
+        let config = load("config_errors");
+        let err = config.args::<getter::StructArgs>("get").unwrap_err();
+
+        assert_eq!(
+            err.msg,
+            "invalid `get` of getset2.toml, expected a string but found boolean"
+        );
+
+        let err = config.args::<setter::StructArgs>("set").unwrap_err();
+
+        assert!(
+            err.msg.starts_with("invalid `set` of getset2.toml"),
+            "{}",
+            err.msg
+        );
+    }
+
+    #[test]
+    fn config_none() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+        assert!(Config::load(&dir).unwrap().is_none());
+    }
+}
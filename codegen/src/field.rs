@@ -0,0 +1,132 @@
+use derive_more::Deref;
+use proc_macro2::Span;
+use syn::{parse_quote_spanned, spanned::Spanned, Expr, Index, Member};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Access {
+    /// The field is accessed through `self`.
+    Field,
+    /// The field is bound by a `match` pattern instead of accessed through `self`.
+    Binding,
+    /// The field is stored as an `Option<T>` slot through `self`, like a builder.
+    Slot,
+}
+
+#[derive(Clone, Debug, Deref)]
+pub struct Field {
+    #[deref]
+    pub field: syn::Field,
+    pub idx: usize,
+    pub access: Access,
+}
+
+impl Field {
+    pub fn new(field: syn::Field, idx: usize) -> Self {
+        Field {
+            field,
+            idx,
+            access: Access::Field,
+        }
+    }
+
+    pub fn binding(field: syn::Field, idx: usize) -> Self {
+        Field {
+            field,
+            idx,
+            access: Access::Binding,
+        }
+    }
+
+    pub fn slot(field: syn::Field, idx: usize) -> Self {
+        Field {
+            field,
+            idx,
+            access: Access::Slot,
+        }
+    }
+
+    pub fn is_binding(&self) -> bool {
+        self.access == Access::Binding
+    }
+
+    /// The span of the field name, or the type of an unnamed field.
+    pub fn name_span(&self) -> Span {
+        self.field
+            .ident
+            .as_ref()
+            .map_or_else(|| self.field.ty.span(), |ident| ident.span())
+    }
+
+    pub fn member(&self) -> Member {
+        match self.field.ident {
+            Some(ref name) => Member::Named(name.clone()),
+            None => Member::Unnamed(Index {
+                index: self.idx as u32,
+                span: self.field.span(),
+            }),
+        }
+    }
+
+    /// The name of the field in the messages, like `foo`, or `0` of an unnamed field.
+    pub fn name_str(&self) -> String {
+        match self.member() {
+            Member::Named(ident) => ident.to_string().trim_start_matches("r#").to_string(),
+            Member::Unnamed(idx) => idx.index.to_string(),
+        }
+    }
+
+    pub fn name(&self) -> Expr {
+        let member = self.member();
+
+        if self.is_binding() {
+            parse_quote_spanned! { self.field.span() =>
+                #member
+            }
+        } else {
+            parse_quote_spanned! { self.field.span() =>
+                self.#member
+            }
+        }
+    }
+
+    /// Assign the value to the field.
+    pub fn assign(&self, value: Expr) -> Expr {
+        let name = self.name();
+
+        if self.access == Access::Slot {
+            parse_quote_spanned! { self.field.span() =>
+                #name = ::core::option::Option::Some(#value)
+            }
+        } else {
+            parse_quote_spanned! { self.field.span() =>
+                #name = #value
+            }
+        }
+    }
+
+    /// The mutable place of the field value, the slot will be initialized with the default value.
+    pub fn place_mut(&self) -> Expr {
+        let name = self.name();
+
+        if self.access == Access::Slot {
+            parse_quote_spanned! { self.field.span() =>
+                #name.get_or_insert_with(::core::default::Default::default)
+            }
+        } else {
+            name
+        }
+    }
+
+    /// The mutable reference to the field value, the slot will be initialized with the default value.
+    pub fn mut_ref(&self) -> Expr {
+        let name = self.name();
+
+        match self.access {
+            Access::Field => parse_quote_spanned! { self.field.span() =>
+                &mut #name
+            },
+            Access::Binding => name,
+            Access::Slot => self.place_mut(),
+        }
+    }
+}
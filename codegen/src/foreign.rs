@@ -0,0 +1,154 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::{format_ident, ToTokens};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    Attribute, Data, DataStruct, DeriveInput, Field, Fields, FieldsNamed, Generics, Ident, Path,
+    Token, TypePath, Visibility,
+};
+
+use crate::{getter, methods::Target, setter, Options};
+
+/// The blocks of `impl_accessors!`, like `impl pb::Request { name: String }`.
+pub struct Accessors(Vec<Block>);
+
+impl Parse for Accessors {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut blocks = vec![];
+
+        while !input.is_empty() {
+            blocks.push(input.parse()?);
+        }
+
+        Ok(Accessors(blocks))
+    }
+}
+
+/// The fields of a type declared in another crate, the accessors are generated in the extension traits.
+struct Block {
+    attrs: Vec<Attribute>,
+    generics: Generics,
+    self_ty: TypePath,
+    fields: FieldsNamed,
+}
+
+impl Parse for Block {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        input.parse::<Token![impl]>()?;
+        let mut generics: Generics = input.parse()?;
+        let self_ty = input.parse()?;
+        generics.where_clause = input.parse()?;
+        let content;
+        let brace_token = braced!(content in input);
+        let named = content.parse_terminated(Field::parse_named, Token![,])?;
+
+        Ok(Block {
+            attrs,
+            generics,
+            self_ty,
+            fields: FieldsNamed { brace_token, named },
+        })
+    }
+}
+
+impl Block {
+    fn ident(&self) -> Ident {
+        self.self_ty
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.clone())
+            .expect("type path")
+    }
+
+    /// Whether the getters and setters are generated, which are chosen by `#[derive(Getter, Setter)]` or both of them.
+    fn derives(&mut self) -> (bool, bool) {
+        let mut derives = None;
+
+        self.attrs.retain(|attr| {
+            if !attr.path().is_ident("derive") {
+                return true;
+            }
+
+            let (getter, setter) = derives.get_or_insert((false, false));
+
+            match attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated) {
+                Ok(paths) => {
+                    for path in paths {
+                        match path.segments.last().map(|segment| segment.ident.to_string()) {
+                            Some(name) if name == "Getter" => *getter = true,
+                            Some(name) if name == "Setter" => *setter = true,
+                            _ => emit_error!(
+                                path,
+                                "#[derive({})] isn't supported by `impl_accessors!`", path.to_token_stream();
+                                help = "only `Getter` and `Setter` can be derived"
+                            ),
+                        }
+                    }
+                }
+                Err(err) => emit_error!(err.span(), "invalid #[derive(..)] attribute, {}", err),
+            }
+
+            false
+        });
+
+        derives.unwrap_or((true, true))
+    }
+
+    fn expand(mut self, options: &Options) -> TokenStream {
+        let (getter, setter) = self.derives();
+        let ident = self.ident();
+        let input = DeriveInput {
+            attrs: self.attrs,
+            vis: Visibility::Inherited,
+            ident: ident.clone(),
+            generics: self.generics,
+            data: Data::Struct(DataStruct {
+                struct_token: Default::default(),
+                fields: Fields::Named(self.fields),
+                semi_token: None,
+            }),
+        };
+        // the traits are implemented for the declared type instead of its last segment, which may not be in scope
+        let target = Target {
+            self_ty: Some(self.self_ty),
+            ..Target::default()
+        };
+        let mut tokens = TokenStream::new();
+
+        // the inherent methods can't be implemented for a foreign type, so the methods are always in a trait
+        if getter {
+            let mut input = input.clone();
+            let trait_name = format_ident!("{}Getters", ident);
+
+            input
+                .attrs
+                .push(parse_quote! { #[get(trait = #trait_name)] });
+            tokens.extend(getter::expand(input, options, &target));
+        }
+
+        if setter {
+            let mut input = input;
+            let trait_name = format_ident!("{}Setters", ident);
+
+            input
+                .attrs
+                .push(parse_quote! { #[set(trait = #trait_name)] });
+            tokens.extend(setter::expand(input, options, &target));
+        }
+
+        tokens
+    }
+}
+
+pub fn expand(accessors: Accessors, options: &Options) -> TokenStream {
+    accessors
+        .0
+        .into_iter()
+        .map(|block| block.expand(options))
+        .collect()
+}
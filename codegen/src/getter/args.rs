@@ -0,0 +1,238 @@
+use merge::Merge;
+use structmeta::{Flag, NameArgs, NameValue, StructMeta};
+use syn::{ExprPath, Ident, LitBool, LitStr, Meta, Path, Type};
+
+use crate::{
+    args::{self, merge_flag, merge_name_args, ArgNames, Flatten, Toggle},
+    path::{merge_paths, PathMethod, Paths},
+    vis::Restricted,
+};
+
+#[derive(Clone, Debug, Default, Merge, StructMeta)]
+pub struct StructArgs {
+    #[struct_meta(name = "pub")]
+    pub vis: Option<NameArgs<Option<Restricted>>>,
+    #[struct_meta(name = "const")]
+    #[merge(strategy = merge_flag)]
+    pub constness: Flag,
+    #[merge(strategy = merge_flag)]
+    pub clone: Flag,
+    #[merge(strategy = merge_flag)]
+    pub copy: Flag,
+    #[merge(strategy = merge_flag)]
+    pub collection: Flag,
+    #[merge(strategy = merge_flag)]
+    pub lifetime: Flag,
+    #[struct_meta(name = "mut")]
+    #[merge(strategy = merge_flag)]
+    pub mutable: Flag,
+    pub opt: Option<NameArgs<Option<Toggle<OptArgs>>>>,
+    #[merge(strategy = merge_flag)]
+    pub slice: Flag,
+    #[merge(strategy = merge_flag)]
+    pub mut_slice: Flag,
+    #[merge(strategy = merge_flag)]
+    pub str: Flag,
+    #[merge(strategy = merge_flag)]
+    pub mut_str: Flag,
+    #[merge(strategy = merge_flag)]
+    pub bytes: Flag,
+    pub prefix: Option<NameValue<LitStr>>,
+    pub suffix: Option<NameValue<LitStr>>,
+    pub inline: Option<NameArgs<Option<Toggle<Ident>>>>,
+    #[merge(strategy = merge_name_args)]
+    pub attrs: Option<NameArgs<Vec<LitStr>>>,
+    #[struct_meta(name = "trait")]
+    pub trait_name: Option<NameValue<Ident>>,
+    pub impl_trait: Option<NameValue<Path>>,
+    #[merge(strategy = merge_paths)]
+    pub path: Option<NameArgs<Paths>>,
+    #[merge(strategy = merge_flag)]
+    pub no_std: Flag,
+    pub field_names: Option<NameArgs<Option<Ident>>>,
+    #[struct_meta(name = "crate")]
+    pub krate: Option<NameValue<Path>>,
+}
+
+impl StructArgs {
+    /// The path of the `getset2` crate, which provides the `Field` and `FieldName` traits.
+    pub fn crate_path(&self) -> Path {
+        args::crate_path(&self.krate)
+    }
+
+    pub fn trait_name(&self) -> Option<&Ident> {
+        self.trait_name.as_ref().map(|arg| &arg.value)
+    }
+
+    pub fn impl_trait(&self) -> Option<&Path> {
+        self.impl_trait.as_ref().map(|arg| &arg.value)
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &PathMethod> {
+        self.path.iter().flat_map(|arg| arg.args.0.iter())
+    }
+
+    pub fn allowed_attrs(&self) -> Option<Vec<String>> {
+        self.attrs
+            .as_ref()
+            .map(|arg| arg.args.iter().map(|s| s.value()).collect())
+    }
+}
+
+#[derive(Clone, Debug, Default, Merge, StructMeta)]
+pub struct FieldArgs {
+    #[struct_meta(name = "pub")]
+    pub vis: Option<NameArgs<Option<Restricted>>>,
+    #[merge(strategy = merge::bool::overwrite_false)]
+    pub skip: bool,
+    #[struct_meta(name = "const")]
+    pub constness: Option<NameArgs<Option<LitBool>>>,
+    pub clone: Option<NameArgs<Option<LitBool>>>,
+    pub copy: Option<NameArgs<Option<LitBool>>>,
+    pub collection: Option<NameArgs<Option<LitBool>>>,
+    pub lifetime: Option<NameArgs<Option<LitBool>>>,
+    #[struct_meta(name = "mut")]
+    pub mutable: Option<NameArgs<Option<LitBool>>>,
+    pub opt: Option<NameArgs<Option<Toggle<OptArgs>>>>,
+    pub slice: Option<NameArgs<Option<ExprPath>>>,
+    pub mut_slice: Option<NameArgs<Option<ExprPath>>>,
+    pub str: Option<NameArgs<Option<ExprPath>>>,
+    pub mut_str: Option<NameArgs<Option<ExprPath>>>,
+    pub bytes: Option<NameArgs<Option<ExprPath>>>,
+    pub borrow: Option<NameArgs<Type>>,
+    pub borrow_mut: Option<NameArgs<Type>>,
+    pub rename: Option<NameArgs<Ident>>,
+    pub prefix: Option<NameValue<LitStr>>,
+    pub suffix: Option<NameValue<LitStr>>,
+    pub impl_trait: Option<NameValue<Path>>,
+    pub field: Option<NameValue<Type>>,
+    pub flatten: Option<NameArgs<Flatten>>,
+    #[merge(strategy = merge_name_args)]
+    pub attr: Option<NameArgs<Vec<Meta>>>,
+}
+
+#[derive(Clone, Debug, Default, Merge, StructMeta)]
+pub struct VariantArgs {
+    #[struct_meta(name = "pub")]
+    pub vis: Option<NameArgs<Option<Restricted>>>,
+    #[merge(strategy = merge::bool::overwrite_false)]
+    pub skip: bool,
+    #[struct_meta(name = "const")]
+    pub constness: Option<NameArgs<Option<LitBool>>>,
+    #[struct_meta(name = "mut")]
+    pub mutable: Option<NameArgs<Option<LitBool>>>,
+    pub rename: Option<NameArgs<Ident>>,
+    pub prefix: Option<NameValue<LitStr>>,
+    pub suffix: Option<NameValue<LitStr>>,
+    #[merge(strategy = merge_name_args)]
+    pub attr: Option<NameArgs<Vec<Meta>>>,
+}
+
+impl ArgNames for StructArgs {
+    const NAMES: &'static [&'static str] = &[
+        "pub",
+        "const",
+        "clone",
+        "copy",
+        "collection",
+        "lifetime",
+        "mut",
+        "opt",
+        "slice",
+        "mut_slice",
+        "str",
+        "mut_str",
+        "bytes",
+        "prefix",
+        "suffix",
+        "inline",
+        "attrs",
+        "trait",
+        "impl_trait",
+        "path",
+        "no_std",
+        "field_names",
+        "crate",
+    ];
+    const OTHER_LEVEL: Option<(&'static str, &'static [&'static str])> =
+        Some(("field", FieldArgs::NAMES));
+}
+
+impl ArgNames for FieldArgs {
+    const NAMES: &'static [&'static str] = &[
+        "pub",
+        "skip",
+        "const",
+        "clone",
+        "copy",
+        "collection",
+        "lifetime",
+        "mut",
+        "opt",
+        "slice",
+        "mut_slice",
+        "str",
+        "mut_str",
+        "bytes",
+        "borrow",
+        "borrow_mut",
+        "rename",
+        "prefix",
+        "suffix",
+        "impl_trait",
+        "field",
+        "flatten",
+        "attr",
+    ];
+    const OTHER_LEVEL: Option<(&'static str, &'static [&'static str])> =
+        Some(("structure", StructArgs::NAMES));
+}
+
+impl ArgNames for VariantArgs {
+    const NAMES: &'static [&'static str] = &[
+        "pub", "skip", "const", "mut", "rename", "prefix", "suffix", "attr",
+    ];
+    const OTHER_LEVEL: Option<(&'static str, &'static [&'static str])> =
+        Some(("field", FieldArgs::NAMES));
+}
+
+#[derive(Clone, Debug, Default, StructMeta)]
+pub struct OptArgs {
+    pub has: Flag,
+    pub or_insert: Flag,
+    pub or_default: Flag,
+}
+
+impl FieldArgs {
+    pub fn borrow_ty(&self) -> Option<&Type> {
+        self.borrow.as_ref().map(|arg| &arg.args)
+    }
+
+    pub fn borrow_mut_ty(&self) -> Option<&Type> {
+        self.borrow_mut.as_ref().map(|arg| &arg.args)
+    }
+
+    pub fn slice_path(&self) -> Option<&ExprPath> {
+        extract_path(&self.slice)
+    }
+
+    pub fn mut_slice_path(&self) -> Option<&ExprPath> {
+        extract_path(&self.mut_slice)
+    }
+
+    pub fn str_path(&self) -> Option<&ExprPath> {
+        extract_path(&self.str)
+    }
+
+    pub fn mut_str_path(&self) -> Option<&ExprPath> {
+        extract_path(&self.mut_str)
+    }
+
+    pub fn bytes_path(&self) -> Option<&ExprPath> {
+        extract_path(&self.bytes)
+    }
+}
+
+fn extract_path(p: &Option<NameArgs<Option<ExprPath>>>) -> Option<&ExprPath> {
+    p.as_ref().and_then(|arg| arg.args.as_ref())
+}
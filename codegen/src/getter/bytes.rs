@@ -1,8 +1,7 @@
 use proc_macro2::Span;
-use proc_macro_error::abort;
 use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Ident, ItemFn, Type};
 
-use crate::{args, ty::TypeExt};
+use crate::ty::TypeExt;
 
 use super::{gen, Context};
 
@@ -22,15 +21,7 @@ pub fn getter(ctx: &Context) -> ItemFn {
         } else {
             let field_name = ctx.field.name();
 
-            let method_name = if is_vec_u8(&ctx.field.ty) || is_array_u8(&ctx.field.ty) {
-                "as_slice"
-            } else if is_cstr_or_cstring(&ctx.field.ty) {
-                "to_bytes"
-            } else {
-                "as_bytes"
-            };
-
-            let method = Ident::new(method_name, Span::call_site());
+            let method = bytes_method(&ctx.field.ty);
 
             parse_quote_spanned!(ctx.field.span() => {
                 #field_name.#method()
@@ -43,27 +34,27 @@ pub fn getter(ctx: &Context) -> ItemFn {
 
 impl Context<'_> {
     pub fn is_bytes(&self) -> bool {
-        if args::merge_bool(&self.field.args.bytes, &self.struct_args.bytes).unwrap_or_default() {
-            if is_well_known_type(&self.field.ty)
-                || is_vec_u8(&self.field.ty)
-                || is_array_u8(&self.field.ty)
-                || self.field.args.bytes_path().is_some()
-            {
-                return true;
-            }
-
-            if self.field.args.bytes.is_some() {
-                abort!(
-                    self.field.ty.span(),
-                    "#[get(bytes(...))] need to specify the function that accesses the bytes"
-                );
-            }
-        }
-
-        false
+        self.value_mode(&self.field.args.bytes, &self.struct_args.bytes)
+            && (is_well_known_type(self.value_ty())
+                || is_vec_u8(self.value_ty())
+                || is_array_u8(self.value_ty())
+                || self.field.args.bytes_path().is_some())
     }
 }
 
+/// The method to access the bytes of a well-known type.
+pub fn bytes_method(ty: &Type) -> Ident {
+    let method_name = if is_vec_u8(ty) || is_array_u8(ty) {
+        "as_slice"
+    } else if is_cstr_or_cstring(ty) {
+        "to_bytes"
+    } else {
+        "as_bytes"
+    };
+
+    Ident::new(method_name, Span::call_site())
+}
+
 const WELL_KNOWN_TYPES: &[&str] = &["String", "CString", "OsString"];
 const WELL_KNOWN_REF_TYPES: &[&str] = &["str", "CStr", "OsStr"];
 
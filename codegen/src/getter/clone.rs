@@ -1,7 +1,5 @@
 use syn::{parse_quote_spanned, ItemFn};
 
-use crate::args;
-
 use super::{copy, Context};
 
 pub fn getter(ctx: &Context) -> ItemFn {
@@ -11,7 +9,7 @@ pub fn getter(ctx: &Context) -> ItemFn {
         let ref_field_name = ctx.field.ref_name();
 
         parse_quote_spanned!(ctx.attr_span() => {
-            ::std::clone::Clone::clone( #ref_field_name )
+            ::core::clone::Clone::clone( #ref_field_name )
         })
     };
 
@@ -20,6 +18,6 @@ pub fn getter(ctx: &Context) -> ItemFn {
 
 impl Context<'_> {
     pub fn is_cloneable(&self) -> bool {
-        args::merge_bool(&self.field.args.clone, &self.struct_args.clone).unwrap_or_default()
+        self.value_mode(&self.field.args.clone, &self.struct_args.clone)
     }
 }
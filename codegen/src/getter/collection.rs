@@ -0,0 +1,282 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::format_ident;
+use syn::{parse_quote_spanned, spanned::Spanned, ItemFn, Type};
+
+use crate::{args, ty};
+
+use super::Context;
+
+/// A well-known collection type and its generic arguments.
+pub struct Collection<'a> {
+    pub name: &'static str,
+    pub args: Vec<&'a Type>,
+}
+
+impl<'a> Collection<'a> {
+    fn is_indexed(&self) -> bool {
+        matches!(self.name, "Vec" | "VecDeque")
+    }
+
+    fn is_set(&self) -> bool {
+        ty::is_set(self.name)
+    }
+
+    fn is_map(&self) -> bool {
+        ty::is_map(self.name)
+    }
+
+    fn is_iter_mut(&self) -> bool {
+        !self.is_set() && self.name != "BinaryHeap"
+    }
+
+    fn item_ty(&self) -> &'a Type {
+        self.args[0]
+    }
+
+    fn value_ty(&self) -> &'a Type {
+        self.args[1]
+    }
+
+    /// The bounds of the key to lookup a set or a map.
+    fn key_bounds(&self) -> TokenStream {
+        ty::lookup_bounds(self.name)
+    }
+}
+
+pub fn getters(ctx: &Context) -> Vec<ItemFn> {
+    let collection = ctx.collection();
+    let attrs = &ctx.field.attrs;
+    let vis = ctx.vis();
+    let method_name = ctx.method_name();
+    let name = ctx.field.name();
+    let is_mutable = ctx.is_mutable();
+    let item_ty = collection.item_ty();
+    let mut getters = vec![];
+
+    let len_name = format_ident!("{}_len", method_name);
+    let is_empty_name = format_ident!("{}_is_empty", method_name);
+    let iter_name = format_ident!("{}_iter", method_name);
+
+    getters.push(parse_quote_spanned! { ctx.field.span() =>
+        #( #attrs )*
+        #[inline(always)]
+        #vis fn #len_name( &self ) -> usize {
+            #name.len()
+        }
+    });
+
+    getters.push(parse_quote_spanned! { ctx.field.span() =>
+        #( #attrs )*
+        #[inline(always)]
+        #vis fn #is_empty_name( &self ) -> bool {
+            #name.is_empty()
+        }
+    });
+
+    if collection.is_map() {
+        let value_ty = collection.value_ty();
+        let key_bounds = collection.key_bounds();
+        let get_name = format_ident!("{}_get", method_name);
+        let contains_name = format_ident!("{}_contains", method_name);
+        let keys_name = format_ident!("{}_keys", method_name);
+        let values_name = format_ident!("{}_values", method_name);
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #iter_name( &self ) -> impl ::core::iter::Iterator<Item = (&#item_ty, &#value_ty)> + '_ {
+                #name.iter()
+            }
+        });
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #get_name<Q>( &self, key: &Q ) -> ::core::option::Option<&#value_ty>
+            where
+                #item_ty: ::core::borrow::Borrow<Q> + #key_bounds,
+                Q: #key_bounds + ?Sized,
+            {
+                #name.get(key)
+            }
+        });
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #contains_name<Q>( &self, key: &Q ) -> bool
+            where
+                #item_ty: ::core::borrow::Borrow<Q> + #key_bounds,
+                Q: #key_bounds + ?Sized,
+            {
+                #name.contains_key(key)
+            }
+        });
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #keys_name( &self ) -> impl ::core::iter::Iterator<Item = &#item_ty> + '_ {
+                #name.keys()
+            }
+        });
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #values_name( &self ) -> impl ::core::iter::Iterator<Item = &#value_ty> + '_ {
+                #name.values()
+            }
+        });
+
+        if is_mutable {
+            let iter_mut_name = format_ident!("{}_iter_mut", method_name);
+            let get_mut_name = format_ident!("{}_get_mut", method_name);
+            let values_mut_name = format_ident!("{}_values_mut", method_name);
+
+            getters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #iter_mut_name( &mut self ) -> impl ::core::iter::Iterator<Item = (&#item_ty, &mut #value_ty)> + '_ {
+                    #name.iter_mut()
+                }
+            });
+
+            getters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #get_mut_name<Q>( &mut self, key: &Q ) -> ::core::option::Option<&mut #value_ty>
+                where
+                    #item_ty: ::core::borrow::Borrow<Q> + #key_bounds,
+                    Q: #key_bounds + ?Sized,
+                {
+                    #name.get_mut(key)
+                }
+            });
+
+            getters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #values_mut_name( &mut self ) -> impl ::core::iter::Iterator<Item = &mut #value_ty> + '_ {
+                    #name.values_mut()
+                }
+            });
+        }
+
+        return getters;
+    }
+
+    let contains_name = format_ident!("{}_contains", method_name);
+
+    getters.push(parse_quote_spanned! { ctx.field.span() =>
+        #( #attrs )*
+        #[inline(always)]
+        #vis fn #iter_name( &self ) -> impl ::core::iter::Iterator<Item = &#item_ty> + '_ {
+            #name.iter()
+        }
+    });
+
+    if collection.is_set() {
+        let key_bounds = collection.key_bounds();
+        let get_name = format_ident!("{}_get", method_name);
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #get_name<Q>( &self, value: &Q ) -> ::core::option::Option<&#item_ty>
+            where
+                #item_ty: ::core::borrow::Borrow<Q> + #key_bounds,
+                Q: #key_bounds + ?Sized,
+            {
+                #name.get(value)
+            }
+        });
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #contains_name<Q>( &self, value: &Q ) -> bool
+            where
+                #item_ty: ::core::borrow::Borrow<Q> + #key_bounds,
+                Q: #key_bounds + ?Sized,
+            {
+                #name.contains(value)
+            }
+        });
+    } else if collection.name != "BinaryHeap" {
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #contains_name<Q>( &self, value: &Q ) -> bool
+            where
+                #item_ty: ::core::cmp::PartialEq<Q>,
+                Q: ?Sized,
+            {
+                #name.iter().any(|item| item == value)
+            }
+        });
+    }
+
+    if collection.is_indexed() {
+        let get_name = format_ident!("{}_get", method_name);
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #get_name( &self, idx: usize ) -> ::core::option::Option<&#item_ty> {
+                #name.get(idx)
+            }
+        });
+    }
+
+    if is_mutable && collection.is_iter_mut() {
+        let iter_mut_name = format_ident!("{}_iter_mut", method_name);
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #iter_mut_name( &mut self ) -> impl ::core::iter::Iterator<Item = &mut #item_ty> + '_ {
+                #name.iter_mut()
+            }
+        });
+
+        if collection.is_indexed() {
+            let get_mut_name = format_ident!("{}_get_mut", method_name);
+
+            getters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #get_mut_name( &mut self, idx: usize ) -> ::core::option::Option<&mut #item_ty> {
+                    #name.get_mut(idx)
+                }
+            });
+        }
+    }
+
+    getters
+}
+
+impl Context<'_> {
+    pub fn is_collection(&self) -> bool {
+        args::merge_bool(&self.field.args.collection, &self.struct_args.collection)
+            .unwrap_or_default()
+            && recognize(&self.field.ty).is_some()
+    }
+
+    pub fn collection(&self) -> Collection<'_> {
+        match recognize(&self.field.ty) {
+            Some(collection) => collection,
+            None => {
+                abort!(
+                    self.field.ty.span(),
+                    "field should be a well-known collection type"
+                );
+            }
+        }
+    }
+}
+
+fn recognize(ty: &Type) -> Option<Collection<'_>> {
+    ty::collection_args(ty).map(|(name, args)| Collection { name, args })
+}
@@ -0,0 +1,320 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::emit_error;
+use quote::{format_ident, ToTokens};
+use syn::{parse_quote_spanned, spanned::Spanned, Ident, ItemFn, Path, Token, Type, Visibility};
+
+use structmeta::NameArgs;
+
+use crate::{
+    args::{self, AsBool, Mode},
+    field::Field as BaseField,
+};
+
+use super::{Field, FieldArgs, StructArgs};
+
+#[derive(Clone, Debug)]
+pub struct Context<'a> {
+    pub struct_args: &'a StructArgs,
+    pub struct_args_span: Option<Span>,
+    pub struct_vis: Visibility,
+    pub field: Field,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(
+        struct_args: &'a StructArgs,
+        struct_args_span: Option<Span>,
+        struct_vis: &Visibility,
+        field: BaseField,
+    ) -> Self {
+        let (field_args, field_args_span, mut field_attrs) =
+            args::extract::<FieldArgs, _>(field.attrs.clone(), "get", struct_args.allowed_attrs());
+
+        if let Some(meta) = field_args.attr.as_ref() {
+            field_attrs.extend(meta.args.iter().map(|meta| {
+                parse_quote_spanned! { meta.span() =>
+                    #[ #meta ]
+                }
+            }));
+        }
+
+        Self {
+            struct_args,
+            struct_args_span,
+            struct_vis: struct_vis.clone(),
+            field: Field::new(field, field_args, field_args_span, field_attrs),
+        }
+    }
+
+    pub fn attr_span(&self) -> Span {
+        self.field
+            .args_span
+            .or(self.struct_args_span)
+            .unwrap_or(self.field.span())
+    }
+
+    pub fn vis(&self) -> Visibility {
+        args::vis(&self.field.args.vis, &self.struct_args.vis, &self.field.vis)
+    }
+
+    pub fn constness(&self) -> Option<Token![const]> {
+        args::constness(&self.field.args.constness, &self.struct_args.constness)
+    }
+
+    /// The existing trait which is implemented by the getters instead of the inherent methods.
+    pub fn impl_trait(&self) -> Option<&Path> {
+        self.field
+            .args
+            .impl_trait
+            .as_ref()
+            .map(|arg| &arg.value)
+            .or_else(|| self.struct_args.impl_trait())
+    }
+
+    pub fn method_name(&self) -> Ident {
+        format_ident!(
+            "{}{}{}",
+            self.prefix(),
+            self.field.basename(),
+            self.suffix()
+        )
+    }
+
+    pub fn mut_method_name(&self) -> Ident {
+        format_ident!(
+            "{}{}{}_mut",
+            self.prefix(),
+            self.field.basename(),
+            self.suffix()
+        )
+    }
+
+    pub fn prefix(&self) -> String {
+        args::prefix(&self.field.args.prefix, &self.struct_args.prefix).unwrap_or_default()
+    }
+
+    pub fn suffix(&self) -> String {
+        args::suffix(&self.field.args.suffix, &self.struct_args.suffix).unwrap_or_default()
+    }
+}
+
+impl<'a> ToTokens for Context<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for getter in self.getters() {
+            getter.to_tokens(tokens);
+        }
+    }
+}
+
+impl Context<'_> {
+    pub fn getters(&self) -> Vec<ItemFn> {
+        if self.field.args.skip {
+            return vec![];
+        }
+
+        self.check_args();
+
+        if self.flatten().is_some() {
+            return super::flatten::getters(self);
+        }
+
+        if self.is_collection() {
+            return super::collection::getters(self);
+        }
+
+        let mut getters = self
+            .getter()
+            .into_iter()
+            .chain(self.mut_getter())
+            .collect::<Vec<_>>();
+
+        if self.is_option() {
+            getters.extend(super::option::utilities(self));
+        }
+
+        getters
+    }
+
+    pub fn getter(&self) -> Option<ItemFn> {
+        if self.field.args.skip {
+            return None;
+        }
+
+        let mut getter = if self.is_option() {
+            super::option::getter(self)
+        } else if self.is_copyable() && !self.is_lifetime() {
+            // the shared reference is copied as is, instead of the value behind it
+            super::copy::getter(self)
+        } else if self.is_cloneable() {
+            super::clone::getter(self)
+        } else if self.is_slice() {
+            super::slice::getter(self)
+        } else if self.is_str() {
+            super::str::getter(self)
+        } else if self.is_bytes() {
+            super::bytes::getter(self)
+        } else if let Some(borrowed_ty) = self.field.args.borrow_ty() {
+            super::borrow::getter(self, borrowed_ty)
+        } else {
+            super::gen::getter(self)
+        };
+
+        if let Some(lifetime) = self.ref_lifetime() {
+            super::lifetime::bind(&mut getter, lifetime);
+        }
+
+        Some(getter)
+    }
+
+    pub fn mut_getter(&self) -> Option<ItemFn> {
+        if self.field.args.skip {
+            return None;
+        }
+
+        if self.is_mutable() || self.is_mut_slice() || self.is_mut_str() || self.is_borrow_mut() {
+            Some(if self.is_option() {
+                super::option::mut_getter(self)
+            } else if self.is_mut_slice() {
+                super::slice::mut_getter(self)
+            } else if self.is_mut_str() {
+                super::str::mut_getter(self)
+            } else if let Some(borrowed_ty) = self.field.args.borrow_mut_ty() {
+                super::borrow::mut_getter(self, borrowed_ty)
+            } else {
+                super::gen::mut_getter(self)
+            })
+        } else {
+            None
+        }
+    }
+
+    fn check_args(&self) {
+        let (args, struct_args) = (&self.field.args, self.struct_args);
+
+        if let Some(span) = args::misapplied_mode(&args.opt, self.is_option()) {
+            emit_error!(span, "#[get(opt)] should be applied to an `Option<T>` type");
+        }
+
+        if let Some(span) = args::misapplied_mode(&args.collection, self.is_collection()) {
+            emit_error!(
+                span,
+                "#[get(collection)] supports only some of the well-known collection types";
+                help = "the field should be one of `Vec`, `VecDeque`, `LinkedList`, `BinaryHeap`, `HashSet`, `BTreeSet`, `HashMap` or `BTreeMap`"
+            );
+        }
+
+        if let Some(span) = args::misapplied_mode(&args.slice, self.is_slice()) {
+            emit_error!(
+                span,
+                "#[get(slice)] should be applied to a `Vec<T>` or an array `[T; N]` type"
+            );
+        }
+
+        if let Some(span) = args::misapplied_mode(&args.mut_slice, self.is_mut_slice()) {
+            emit_error!(
+                span,
+                "#[get(mut_slice)] should be applied to a `Vec<T>` or an array `[T; N]` type"
+            );
+        }
+
+        if let Some(span) = args::misapplied_mode(&args.lifetime, self.is_lifetime()) {
+            emit_error!(
+                span,
+                "#[get(lifetime)] should be applied to a shared reference like `&'a T` or `Option<&'a T>`"
+            );
+        }
+
+        if let Some(span) = args::misapplied_mode(&args.str, self.is_str()) {
+            emit_error!(span, "#[get(str)] should be applied to a String type");
+        }
+
+        if let Some(span) = args::misapplied_mode(&args.mut_str, self.is_mut_str()) {
+            emit_error!(span, "#[get(mut_str)] should be applied to a String type");
+        }
+
+        if let Some(span) = args::misapplied_mode(&args.bytes, self.is_bytes()) {
+            emit_error!(
+                span,
+                "#[get(bytes(...))] need to specify the function that accesses the bytes"
+            );
+        }
+
+        args::check_exclusive_modes(
+            "get",
+            &[
+                Mode::new("copy", self.is_copyable(), &args.copy, &struct_args.copy),
+                Mode::new(
+                    "clone",
+                    self.is_cloneable(),
+                    &args.clone,
+                    &struct_args.clone,
+                ),
+                Mode::new("slice", self.is_slice(), &args.slice, &struct_args.slice),
+                Mode::new("str", self.is_str(), &args.str, &struct_args.str),
+                Mode::new("bytes", self.is_bytes(), &args.bytes, &struct_args.bytes),
+                Mode::new(
+                    "borrow",
+                    self.is_borrow(),
+                    &args.borrow,
+                    &None::<NameArgs<()>>,
+                ),
+            ],
+            self.attr_span(),
+        );
+
+        args::check_exclusive_modes(
+            "get",
+            &[
+                Mode::new(
+                    "mut_slice",
+                    self.is_mut_slice(),
+                    &args.mut_slice,
+                    &struct_args.mut_slice,
+                ),
+                Mode::new(
+                    "mut_str",
+                    self.is_mut_str(),
+                    &args.mut_str,
+                    &struct_args.mut_str,
+                ),
+                Mode::new(
+                    "borrow_mut",
+                    self.is_borrow_mut(),
+                    &args.borrow_mut,
+                    &None::<NameArgs<()>>,
+                ),
+            ],
+            self.attr_span(),
+        );
+    }
+
+    /// Whether a value mode like `copy` is applied to the field.
+    pub fn value_mode<F: AsBool, S: AsBool>(&self, field_mode: &F, struct_mode: &S) -> bool {
+        let args = &self.field.args;
+        let has_field_mode = args.copy.bool()
+            || args.clone.bool()
+            || args.slice.bool()
+            || args.str.bool()
+            || args.bytes.bool()
+            || args.borrow.bool();
+
+        args::exclusive_mode(field_mode, struct_mode, has_field_mode)
+    }
+
+    /// Whether a mutable mode like `mut_slice` is applied to the field.
+    pub fn mut_mode<F: AsBool, S: AsBool>(&self, field_mode: &F, struct_mode: &S) -> bool {
+        let args = &self.field.args;
+        let has_field_mode = args.mut_slice.bool() || args.mut_str.bool() || args.borrow_mut.bool();
+
+        args::exclusive_mode(field_mode, struct_mode, has_field_mode)
+    }
+
+    /// The type of the value which the modes are applied to, the inner type of `Option<T>` for `#[get(opt)]`.
+    pub fn value_ty(&self) -> &Type {
+        if self.is_option() {
+            self.option_inner_ty()
+        } else {
+            &self.field.ty
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use syn::{parse_quote_spanned, spanned::Spanned, ItemFn};
 
-use crate::{args, ty::TypeExt};
+use crate::ty::TypeExt;
 
 use super::{gen, Context};
 
@@ -16,17 +16,11 @@ pub fn getter(ctx: &Context) -> ItemFn {
     };
 
     getter.block = {
-        let field_name = ctx.field.name();
-
-        if ctx.field.ty.ref_elem_ty().is_some() {
-            parse_quote_spanned! ( ctx.field.span() => {
-                * #field_name
-            })
-        } else {
-            parse_quote_spanned! ( ctx.field.span() => {
-                #field_name
-            })
-        }
+        let value = ctx.field.value();
+
+        parse_quote_spanned! ( ctx.field.span() => {
+            #value
+        })
     };
 
     getter
@@ -34,6 +28,6 @@ pub fn getter(ctx: &Context) -> ItemFn {
 
 impl Context<'_> {
     pub fn is_copyable(&self) -> bool {
-        args::merge_bool(&self.field.args.copy, &self.struct_args.copy).unwrap_or_default()
+        self.value_mode(&self.field.args.copy, &self.struct_args.copy)
     }
 }
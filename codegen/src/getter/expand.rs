@@ -0,0 +1,279 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::{abort, emit_error};
+use quote::{quote, ToTokens};
+use syn::{
+    parse_quote, parse_quote_spanned, punctuated::Punctuated, spanned::Spanned, token::Comma, Data,
+    DataEnum, DataStruct, DeriveInput, Expr, Fields, FieldsNamed, FieldsUnnamed, Ident, ItemFn,
+    ReturnType, Stmt, Visibility,
+};
+
+use crate::{
+    args,
+    field::Field,
+    methods::{self, MethodNames, Target, TraitDecl, TraitImpls},
+    Options,
+};
+
+use super::{Context, StructArgs, Variant};
+
+pub fn expand(input: DeriveInput, options: &Options, target: &Target) -> TokenStream {
+    let DeriveInput {
+        attrs, vis, data, ..
+    } = input.clone();
+
+    let (struct_args, struct_args_span) =
+        args::extract_struct::<StructArgs, _>(attrs, "get", options);
+    let mut impls = TraitImpls::default();
+    let mut field_impls = TokenStream::new();
+
+    let getters = match data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            let fields = match fields {
+                Fields::Named(FieldsNamed { named, .. }) => named,
+                Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => unnamed,
+                Fields::Unit => {
+                    return quote!();
+                }
+            };
+            let fields = fields
+                .into_iter()
+                .enumerate()
+                .map(|(field_idx, field)| Field::new(field, field_idx))
+                .collect::<Vec<_>>();
+
+            // the traits are implemented once, rather than by each of the compat derives
+            if target.mutable.is_none() {
+                field_impls.extend(super::traits::field_names(
+                    &struct_args,
+                    &input,
+                    &vis,
+                    &fields,
+                ));
+            }
+
+            let mut names = MethodNames::new("Getter");
+            let mut getters = fields
+                .into_iter()
+                .flat_map(|field| {
+                    let ctx = Context::new(&struct_args, struct_args_span, &vis, field);
+
+                    if target.mutable.is_none() {
+                        field_impls.extend(super::traits::field_impls(&ctx, &input, target));
+                    }
+
+                    match ctx.impl_trait() {
+                        Some(path) => {
+                            impls.push(path, ctx.getters());
+                            vec![]
+                        }
+                        None => names.insert(ctx.field.name_span(), ctx.getters()),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            for getter in super::path::getters(&struct_args) {
+                getters.extend(names.insert(getter.sig.ident.span(), vec![getter]));
+            }
+
+            getters
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            if let Some(ref arg) = struct_args.path {
+                emit_error!(
+                    arg.name_span,
+                    "#[get(path(..))] can only be applied to structure"
+                );
+            }
+
+            if let Some(ref arg) = struct_args.field_names {
+                emit_error!(
+                    arg.name_span,
+                    "#[get(field_names)] can only be applied to structure"
+                );
+            }
+
+            expand_enum(&struct_args, struct_args_span, &vis, variants, &mut impls)
+        }
+        Data::Union(_) => {
+            abort!(
+                input,
+                "#[derive(Getter)] can only be applied to structure or enum"
+            )
+        }
+    };
+
+    let inline = args::inline(&struct_args.inline, "get");
+    let mut tokens = methods::expand(
+        &input,
+        target,
+        struct_args.trait_name().map(|name| TraitDecl {
+            name,
+            vis: args::vis(&None, &struct_args.vis, &vis),
+            doc: "The getters of",
+            dyn_trait: false,
+        }),
+        args::alloc_crate(struct_args.no_std.value()),
+        methods::inline(getters, &inline),
+    );
+
+    tokens.extend(impls.expand(&input, target, &inline));
+    tokens.extend(field_impls);
+    tokens
+}
+
+/// A named field shared by some variants of an enum.
+struct SharedField {
+    field: syn::Field,
+    variants: Vec<Ident>,
+    mismatched: Option<Span>,
+}
+
+fn expand_enum(
+    struct_args: &StructArgs,
+    struct_args_span: Option<Span>,
+    vis: &Visibility,
+    variants: Punctuated<syn::Variant, Comma>,
+    impls: &mut TraitImpls,
+) -> Vec<ItemFn> {
+    let mut shared_fields: Vec<SharedField> = vec![];
+
+    for variant in &variants {
+        let fields = match variant.fields {
+            Fields::Named(FieldsNamed { ref named, .. }) => named,
+            _ => continue,
+        };
+
+        for field in fields {
+            let shared = shared_fields
+                .iter_mut()
+                .find(|shared| shared.field.ident == field.ident);
+
+            if let Some(shared) = shared {
+                if shared.mismatched.is_none()
+                    && shared.field.ty.to_token_stream().to_string()
+                        != field.ty.to_token_stream().to_string()
+                {
+                    shared.mismatched = Some(field.ty.span());
+                }
+
+                shared.field.attrs.extend(
+                    field
+                        .attrs
+                        .iter()
+                        .filter(|attr| attr.path().is_ident("get"))
+                        .cloned(),
+                );
+                shared.variants.push(variant.ident.clone());
+            } else {
+                shared_fields.push(SharedField {
+                    field: syn::Field {
+                        vis: vis.clone(),
+                        ..field.clone()
+                    },
+                    variants: vec![variant.ident.clone()],
+                    mismatched: None,
+                });
+            }
+        }
+    }
+
+    let mut names = MethodNames::new("Getter");
+    let getters = shared_fields
+        .into_iter()
+        .enumerate()
+        .map(|(field_idx, shared)| {
+            let ctx = Context::new(
+                struct_args,
+                struct_args_span,
+                vis,
+                Field::binding(shared.field, field_idx),
+            );
+
+            if let Some(ref arg) = ctx.field.args.field {
+                emit_error!(
+                    arg.name_span,
+                    "#[get(field = ..)] can only be applied to the fields of structure"
+                );
+            }
+
+            if ctx.field.args.skip {
+                return vec![];
+            }
+
+            if let Some(span) = shared.mismatched {
+                emit_error!(
+                    span,
+                    "field `{}` should have the same type in all variants",
+                    ctx.field.ident.as_ref().unwrap();
+                    help = "use #[get(skip)] to skip generating getter for the field"
+                );
+
+                return vec![];
+            }
+
+            let partial = shared.variants.len() < variants.len();
+            let getters = ctx
+                .getters()
+                .into_iter()
+                .map(|getter| match_variants(getter, &ctx, &shared.variants, partial))
+                .collect::<Vec<_>>();
+
+            match ctx.impl_trait() {
+                Some(path) => {
+                    impls.push(path, getters);
+                    vec![]
+                }
+                None => names.insert(ctx.field.name_span(), getters),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let only_variant = variants.len() == 1;
+    let accessors = variants.iter().flat_map(|variant| {
+        let accessors =
+            Variant::new(struct_args, vis.clone(), variant.clone(), only_variant).accessors();
+
+        names.insert(variant.ident.span(), accessors)
+    });
+
+    getters.into_iter().flatten().chain(accessors).collect()
+}
+
+/// Wrap the body of getter with a `match` expression which binds the field in the variants.
+///
+/// The result will be wrapped as `Option<T>` if the field is not present in all variants.
+fn match_variants(mut getter: ItemFn, ctx: &Context, variants: &[Ident], partial: bool) -> ItemFn {
+    let field_name = ctx.field.ident.as_ref().unwrap();
+    let body: Expr = match getter.block.stmts.as_slice() {
+        [Stmt::Expr(expr, None)] => expr.clone(),
+        _ => {
+            let block = &getter.block;
+
+            parse_quote! { #block }
+        }
+    };
+
+    getter.block = if partial {
+        parse_quote_spanned! { ctx.field.span() => {
+            match self {
+                #( Self::#variants { #field_name, .. } )|* => ::core::option::Option::Some(#body),
+                _ => ::core::option::Option::None,
+            }
+        }}
+    } else {
+        parse_quote_spanned! { ctx.field.span() => {
+            match self {
+                #( Self::#variants { #field_name, .. } )|* => #body
+            }
+        }}
+    };
+
+    if partial {
+        getter.sig.output = match getter.sig.output {
+            ReturnType::Type(_, ty) => parse_quote! { -> ::core::option::Option<#ty> },
+            ReturnType::Default => parse_quote! { -> ::core::option::Option<()> },
+        };
+    }
+
+    getter
+}
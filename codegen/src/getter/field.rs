@@ -0,0 +1,90 @@
+use derive_more::{Constructor, Deref};
+use proc_macro2::Span;
+use syn::{parse_quote, Attribute, Expr, Ident};
+
+use crate::{args, field::Field as BaseField, ty::TypeExt};
+
+use super::FieldArgs;
+
+#[derive(Clone, Debug, Constructor, Deref)]
+pub struct Field {
+    #[deref]
+    pub field: BaseField,
+    pub args: FieldArgs,
+    pub args_span: Option<Span>,
+    pub attrs: Vec<Attribute>,
+}
+
+impl Field {
+    pub fn basename(&self) -> Ident {
+        args::name(&self.args.rename, &self.field.ident, self.field.idx)
+    }
+
+    pub fn reference(&self) -> Expr {
+        let name = self.field.name();
+
+        if self.field.is_binding() {
+            name
+        } else {
+            parse_quote! { & #name }
+        }
+    }
+
+    pub fn mut_reference(&self) -> Expr {
+        let name = self.field.name();
+
+        if self.field.is_binding() {
+            name
+        } else {
+            parse_quote! { &mut #name }
+        }
+    }
+
+    pub fn value(&self) -> Expr {
+        let name = self.field.name();
+
+        match (
+            self.field.is_binding(),
+            self.field.ty.ref_elem_ty().is_some(),
+        ) {
+            (false, false) => name,
+            (false, true) | (true, false) => parse_quote! { * #name },
+            (true, true) => parse_quote! { ** #name },
+        }
+    }
+
+    /// The copy of a shared reference like `&'a T` or `Option<&'a T>`, which keeps the lifetime of the field.
+    pub fn ref_copy(&self) -> Expr {
+        let name = self.field.name();
+
+        if self.field.is_binding() {
+            parse_quote! { * #name }
+        } else {
+            name
+        }
+    }
+
+    pub fn ref_name(&self) -> Expr {
+        if self.field.ty.ref_elem_ty().is_none() {
+            self.reference()
+        } else if self.field.is_binding() {
+            let name = self.field.name();
+
+            parse_quote! { * #name }
+        } else {
+            self.field.name()
+        }
+    }
+
+    pub fn ref_mut_name(&self) -> Expr {
+        if self.field.ty.ref_elem_ty().is_none() {
+            self.mut_reference()
+        } else if self.field.is_binding() {
+            let name = self.field.name();
+
+            parse_quote! { * #name }
+        } else {
+            self.field.name()
+        }
+    }
+}
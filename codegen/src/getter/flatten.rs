@@ -0,0 +1,82 @@
+use quote::format_ident;
+use syn::{parse_quote_spanned, Attribute, ItemFn, Visibility};
+
+use crate::args::{self, Flatten};
+
+use super::Context;
+
+/// Forward the declared getters of a nested field.
+pub fn getters(ctx: &Context) -> Vec<ItemFn> {
+    let flatten = ctx.flatten().unwrap();
+    // the doc comments describe the nested field instead of the forwarded getters
+    let attrs = ctx
+        .field
+        .attrs
+        .iter()
+        .filter(|attr| !attr.path().is_ident("doc"))
+        .collect::<Vec<&Attribute>>();
+    let vis = ctx.flatten_vis();
+    let name = ctx.field.name();
+    let mut getters = vec![];
+
+    for method in &flatten.methods {
+        if !method
+            .modes
+            .check("get", "flatten", &["copy", "clone", "mut"])
+        {
+            continue;
+        }
+
+        let inner_name = flatten.inner_name("", &method.name);
+        let method_name = format_ident!("{}{}{}", ctx.prefix(), method.name, ctx.suffix());
+        let ty = &method.ty;
+
+        getters.push(if method.modes.has("copy") || method.modes.has("clone") {
+            parse_quote_spanned! { method.name.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #method_name( &self ) -> #ty {
+                    #name.#inner_name()
+                }
+            }
+        } else {
+            parse_quote_spanned! { method.name.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #method_name( &self ) -> &#ty {
+                    #name.#inner_name()
+                }
+            }
+        });
+
+        if method.modes.has("mut") {
+            let inner_mut_name = format_ident!("{}_mut", inner_name);
+            let mut_method_name = format_ident!("{}_mut", method_name);
+
+            getters.push(parse_quote_spanned! { method.name.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #mut_method_name( &mut self ) -> &mut #ty {
+                    #name.#inner_mut_name()
+                }
+            });
+        }
+    }
+
+    getters
+}
+
+impl Context<'_> {
+    /// The forwarded getters default to the visibility of the structure instead of the nested field.
+    pub fn flatten_vis(&self) -> Visibility {
+        args::vis(
+            &self.field.args.vis,
+            &self.struct_args.vis,
+            &self.struct_vis,
+        )
+    }
+
+    pub fn flatten(&self) -> Option<&Flatten> {
+        self.field.args.flatten.as_ref().map(|arg| &arg.args)
+    }
+}
@@ -10,28 +10,42 @@ pub fn getter(ctx: &Context) -> ItemFn {
     let constness = ctx.constness();
     let method_name = ctx.method_name();
     let ty = &ctx.field.ty;
-    let field_name = ctx.field.name();
+
+    if ctx.is_lifetime() {
+        let field_copy = ctx.field.ref_copy();
+
+        return parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis #constness fn #method_name( &self ) -> #ty {
+                #field_copy
+            }
+        };
+    }
+
+    let field_ref = ctx.field.reference();
 
     parse_quote_spanned! { ctx.field.span() =>
         #( #attrs )*
         #[inline(always)]
         #vis #constness fn #method_name( &self ) -> & #ty {
-            & #field_name
+            #field_ref
         }
     }
 }
+
 pub fn mut_getter(ctx: &Context) -> ItemFn {
     let attrs = &ctx.field.attrs;
     let vis = ctx.vis();
     let method_name = ctx.mut_method_name();
     let ty = ctx.field.ty.clone();
-    let field_name = ctx.field.name();
+    let field_mut = ctx.field.mut_reference();
 
     parse_quote_spanned! { ctx.field.span() =>
         #( #attrs )*
         #[inline(always)]
         #vis fn #method_name( &mut self ) -> &mut #ty {
-            &mut #field_name
+            #field_mut
         }
     }
 }
@@ -0,0 +1,59 @@
+use syn::{GenericArgument, ItemFn, Lifetime, PathArguments, ReturnType, Type, TypePath};
+
+use crate::{args, ty::TypeExt};
+
+use super::Context;
+
+/// Bind the returned reference to the lifetime of the field, like `&str` to `&'a str` or `Option<&str>` to `Option<&'a str>`.
+pub fn bind(getter: &mut ItemFn, lifetime: &Lifetime) {
+    if let ReturnType::Type(_, ty) = &mut getter.sig.output {
+        bind_ty(ty, lifetime);
+    }
+}
+
+fn bind_ty(ty: &mut Type, lifetime: &Lifetime) {
+    match ty {
+        Type::Reference(ty) if ty.lifetime.is_none() && ty.mutability.is_none() => {
+            ty.lifetime = Some(lifetime.clone());
+        }
+        Type::Path(TypePath { qself: None, path }) => {
+            if let Some(segment) = path.segments.last_mut() {
+                if segment.ident != "Option" {
+                    return;
+                }
+
+                if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    for arg in args.args.iter_mut() {
+                        if let GenericArgument::Type(ty) = arg {
+                            bind_ty(ty, lifetime);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+impl Context<'_> {
+    /// Whether the getter returns a shared reference field like `&'a T` or `Option<&'a T>` with its own lifetime.
+    pub fn is_lifetime(&self) -> bool {
+        args::merge_bool(&self.field.args.lifetime, &self.struct_args.lifetime).unwrap_or_default()
+            && self.field_ref_lifetime().is_some()
+    }
+
+    /// The lifetime of the field for `#[get(lifetime)]`, like `'a` of `&'a str` or `Option<&'a Node>`.
+    pub fn ref_lifetime(&self) -> Option<&Lifetime> {
+        if self.is_lifetime() {
+            self.field_ref_lifetime()
+        } else {
+            None
+        }
+    }
+
+    fn field_ref_lifetime(&self) -> Option<&Lifetime> {
+        let ty = &self.field.ty;
+
+        ty.option_inner_ty().unwrap_or(ty).ref_lifetime()
+    }
+}
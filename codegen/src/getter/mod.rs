@@ -0,0 +1,26 @@
+#![allow(clippy::module_inception)]
+
+mod args;
+mod borrow;
+mod bytes;
+mod clone;
+mod collection;
+mod context;
+mod copy;
+mod expand;
+mod field;
+mod flatten;
+mod gen;
+mod lifetime;
+mod option;
+mod path;
+mod slice;
+mod str;
+mod traits;
+mod variant;
+
+pub use self::args::{FieldArgs, StructArgs, VariantArgs};
+pub use self::context::Context;
+pub use self::expand::expand;
+pub use self::field::Field;
+pub use self::variant::Variant;
@@ -0,0 +1,246 @@
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use structmeta::Flag;
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Expr, ItemFn, Type};
+
+use crate::{args, ty::TypeExt};
+
+use super::{args::OptArgs, bytes, gen, Context};
+
+/// The getter of an `Option<T>` field, which is composed with the value mode of the inner type.
+pub fn getter(ctx: &Context) -> ItemFn {
+    let mut getter = gen::getter(ctx);
+    let inner_ty = ctx.option_inner_ty();
+    let as_ref: Expr = {
+        let ref_field_name = ctx.field.ref_name();
+
+        parse_quote_spanned! { ctx.field.span() =>
+            ::core::option::Option::as_ref( #ref_field_name )
+        }
+    };
+    // `Option<&'a T>` is copied as is to keep the lifetime of the reference
+    let inner_ref = if ctx.is_lifetime() {
+        ctx.field.ref_copy()
+    } else {
+        as_ref.clone()
+    };
+
+    let (ty, value): (Type, Expr) = if ctx.is_copyable() && !ctx.is_lifetime() {
+        (
+            inner_ty.clone(),
+            parse_quote_spanned! { ctx.field.span() => #as_ref.copied() },
+        )
+    } else if ctx.is_cloneable() {
+        (
+            inner_ty.clone(),
+            parse_quote_spanned! { ctx.field.span() => #as_ref.cloned() },
+        )
+    } else if ctx.is_slice() {
+        let item_ty = ctx.slice_inner_ty();
+        let f = ctx.field.args.slice_path().map_or_else(
+            || quote! { ::core::convert::AsRef::<[#item_ty]>::as_ref },
+            |path| quote! { #path },
+        );
+
+        (
+            parse_quote! { &[#item_ty] },
+            parse_quote_spanned! { ctx.field.span() => ::core::option::Option::map( #inner_ref, #f ) },
+        )
+    } else if ctx.is_str() {
+        (
+            parse_quote! { &str },
+            match ctx.field.args.str_path() {
+                Some(path) => parse_quote_spanned! { ctx.field.span() =>
+                    ::core::option::Option::map( #inner_ref, #path )
+                },
+                None if ctx.is_lifetime() => parse_quote_spanned! { ctx.field.span() =>
+                    ::core::option::Option::map( #inner_ref, ::core::convert::AsRef::<str>::as_ref )
+                },
+                None => {
+                    let ref_field_name = ctx.field.ref_name();
+
+                    parse_quote_spanned! { ctx.field.span() =>
+                        ::core::option::Option::as_deref( #ref_field_name )
+                    }
+                }
+            },
+        )
+    } else if ctx.is_bytes() {
+        let f = ctx.field.args.bytes_path().map_or_else(
+            || {
+                let method = bytes::bytes_method(inner_ty);
+
+                quote! { |value| value.#method() }
+            },
+            |path| quote! { #path },
+        );
+
+        (
+            parse_quote! { &[u8] },
+            parse_quote_spanned! { ctx.field.span() => ::core::option::Option::map( #inner_ref, #f ) },
+        )
+    } else if let Some(borrowed_ty) = ctx.field.args.borrow_ty() {
+        (
+            parse_quote! { &#borrowed_ty },
+            parse_quote_spanned! { ctx.field.span() =>
+                ::core::option::Option::map( #inner_ref, ::core::borrow::Borrow::<#borrowed_ty>::borrow )
+            },
+        )
+    } else if ctx.is_lifetime() {
+        (inner_ty.clone(), inner_ref)
+    } else {
+        (parse_quote! { &#inner_ty }, as_ref)
+    };
+
+    getter.sig.output = parse_quote_spanned! { ctx.field.ty.span() =>
+        -> ::core::option::Option<#ty>
+    };
+    getter.block = parse_quote_spanned! { ctx.field.span() => {
+        #value
+    }};
+
+    getter
+}
+
+/// The mutable getter of an `Option<T>` field, which is composed with the mutable mode of the inner type.
+pub fn mut_getter(ctx: &Context) -> ItemFn {
+    let mut getter = gen::mut_getter(ctx);
+    let inner_ty = ctx.option_inner_ty();
+    let as_mut: Expr = {
+        let ref_mut_field_name = ctx.field.ref_mut_name();
+
+        parse_quote_spanned! { ctx.field.span() =>
+            ::core::option::Option::as_mut( #ref_mut_field_name )
+        }
+    };
+
+    let (ty, value): (Type, Expr) = if ctx.is_mut_slice() {
+        let item_ty = ctx.slice_inner_ty();
+        let f = ctx.field.args.mut_slice_path().map_or_else(
+            || quote! { ::core::convert::AsMut::<[#item_ty]>::as_mut },
+            |path| quote! { #path },
+        );
+
+        (
+            parse_quote! { &mut [#item_ty] },
+            parse_quote_spanned! { ctx.field.span() => ::core::option::Option::map( #as_mut, #f ) },
+        )
+    } else if ctx.is_mut_str() {
+        (
+            parse_quote! { &mut str },
+            match ctx.field.args.mut_str_path() {
+                Some(path) => parse_quote_spanned! { ctx.field.span() =>
+                    ::core::option::Option::map( #as_mut, #path )
+                },
+                None => {
+                    let ref_mut_field_name = ctx.field.ref_mut_name();
+
+                    parse_quote_spanned! { ctx.field.span() =>
+                        ::core::option::Option::as_deref_mut( #ref_mut_field_name )
+                    }
+                }
+            },
+        )
+    } else if let Some(borrowed_ty) = ctx.field.args.borrow_mut_ty() {
+        (
+            parse_quote! { &mut #borrowed_ty },
+            parse_quote_spanned! { ctx.field.span() =>
+                ::core::option::Option::map( #as_mut, ::core::borrow::BorrowMut::<#borrowed_ty>::borrow_mut )
+            },
+        )
+    } else {
+        (parse_quote! { &mut #inner_ty }, as_mut)
+    };
+
+    getter.sig.output = parse_quote_spanned! { ctx.field.ty.span() =>
+        -> ::core::option::Option<#ty>
+    };
+    getter.block = parse_quote_spanned! { ctx.field.span() => {
+        #value
+    }};
+
+    getter
+}
+
+/// The `has_x`, `x_or_insert_with` and `x_or_default` getters enabled by `#[get(opt(...))]`.
+pub fn utilities(ctx: &Context) -> Vec<ItemFn> {
+    let attrs = &ctx.field.attrs;
+    let vis = ctx.vis();
+    let method_name = ctx.method_name();
+    let inner_ty = ctx.option_inner_ty();
+    let mut getters = vec![];
+
+    if ctx.has_opt_arg(|arg| &arg.has) {
+        let constness = ctx.constness();
+        let has_name = format_ident!(
+            "{}has_{}{}",
+            ctx.prefix(),
+            ctx.field.basename(),
+            ctx.suffix()
+        );
+        let ref_field_name = ctx.field.ref_name();
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis #constness fn #has_name( &self ) -> bool {
+                ::core::option::Option::is_some( #ref_field_name )
+            }
+        });
+    }
+
+    let ref_mut_field_name = ctx.field.ref_mut_name();
+
+    if ctx.has_opt_arg(|arg| &arg.or_insert) {
+        let or_insert_name = format_ident!("{}_or_insert_with", method_name);
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #or_insert_name<F>( &mut self, f: F ) -> &mut #inner_ty
+            where
+                F: ::core::ops::FnOnce() -> #inner_ty
+            {
+                ::core::option::Option::get_or_insert_with( #ref_mut_field_name, f )
+            }
+        });
+    }
+
+    if ctx.has_opt_arg(|arg| &arg.or_default) {
+        let or_default_name = format_ident!("{}_or_default", method_name);
+
+        getters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #or_default_name( &mut self ) -> &mut #inner_ty {
+                ::core::option::Option::get_or_insert_with(
+                    #ref_mut_field_name,
+                    ::core::default::Default::default,
+                )
+            }
+        });
+    }
+
+    getters
+}
+
+impl Context<'_> {
+    pub fn is_option(&self) -> bool {
+        args::merge_bool(&self.field.args.opt, &self.struct_args.opt).unwrap_or_default()
+            && self.field.ty.option_inner_ty().is_some()
+    }
+
+    pub fn has_opt_arg(&self, flag: fn(&OptArgs) -> &Flag) -> bool {
+        args::toggle_args(&self.field.args.opt, &self.struct_args.opt)
+            .any(|arg| flag(arg).span.is_some())
+    }
+
+    pub fn option_inner_ty(&self) -> &Type {
+        match self.field.ty.option_inner_ty() {
+            Some(ty) => ty,
+            None => {
+                abort!(self.field.ty.span(), "field should be an `Option<T>` type");
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use quote::{format_ident, quote};
+use syn::{parse_quote, parse_quote_spanned, ItemFn, Visibility};
+
+use crate::args;
+
+use super::StructArgs;
+
+/// Generate the getters reading through the field paths of `#[get(path(...))]`.
+pub fn getters(struct_args: &StructArgs) -> Vec<ItemFn> {
+    let vis = args::vis(&None, &struct_args.vis, &Visibility::Inherited);
+    let prefix = args::prefix(&None, &struct_args.prefix).unwrap_or_default();
+    let suffix = args::suffix(&None, &struct_args.suffix).unwrap_or_default();
+
+    struct_args
+        .paths()
+        .filter_map(|method| {
+            if !method.modes.check("get", "path", &["copy", "clone", "str"]) {
+                return None;
+            }
+
+            let method_name = format_ident!("{}{}{}", prefix, method.name, suffix);
+            let ty = method.ty("get")?;
+            let reference = method.path.reference();
+            let optional = method.path.is_optional();
+
+            let (ty, value) = if method.modes.has("copy") {
+                (
+                    quote! { #ty },
+                    if optional {
+                        quote! { #reference.copied() }
+                    } else {
+                        quote! { *#reference }
+                    },
+                )
+            } else if method.modes.has("clone") {
+                (
+                    quote! { #ty },
+                    if optional {
+                        quote! { #reference.cloned() }
+                    } else {
+                        quote! { ::core::clone::Clone::clone(#reference) }
+                    },
+                )
+            } else if method.modes.has("str") {
+                (
+                    quote! { &str },
+                    if optional {
+                        quote! { #reference.map(::core::convert::AsRef::<str>::as_ref) }
+                    } else {
+                        quote! { ::core::convert::AsRef::<str>::as_ref(#reference) }
+                    },
+                )
+            } else {
+                (quote! { &#ty }, reference)
+            };
+            let ty = if optional {
+                parse_quote! { ::core::option::Option<#ty> }
+            } else {
+                ty
+            };
+
+            Some(parse_quote_spanned! { method.name.span() =>
+                #[inline(always)]
+                #vis fn #method_name( &self ) -> #ty {
+                    #value
+                }
+            })
+        })
+        .collect()
+}
@@ -1,7 +1,7 @@
 use proc_macro_error::abort;
 use syn::{parse_quote_spanned, spanned::Spanned, ItemFn, Type};
 
-use crate::{args, ty::TypeExt};
+use crate::ty::TypeExt;
 
 use super::{gen, Context};
 
@@ -65,45 +65,19 @@ pub fn mut_getter(ctx: &Context) -> ItemFn {
 
 impl Context<'_> {
     pub fn is_slice(&self) -> bool {
-        if args::merge_bool(&self.field.args.slice, &self.struct_args.slice).unwrap_or_default() {
-            if self.field.ty.slice_inner_ty().is_some() || self.field.args.slice_path().is_some() {
-                return true;
-            }
-
-            if self.field.args.slice.is_some() {
-                abort!(
-                    self.field.ty.span(),
-                    "#[get(slice)] should be applied to a `Vec<T>` or an array `[T; N]` type"
-                );
-            }
-        }
-
-        false
+        self.value_mode(&self.field.args.slice, &self.struct_args.slice)
+            && (self.value_ty().slice_inner_ty().is_some()
+                || self.field.args.slice_path().is_some())
     }
 
     pub fn is_mut_slice(&self) -> bool {
-        if args::merge_bool(&self.field.args.mut_slice, &self.struct_args.mut_slice)
-            .unwrap_or_default()
-        {
-            if self.field.ty.slice_inner_ty().is_some()
-                || self.field.args.mut_slice_path().is_some()
-            {
-                return true;
-            }
-
-            if self.field.args.mut_slice.is_some() {
-                abort!(
-                    self.field.ty.span(),
-                    "#[get(mut_slice)] should be applied to a `Vec<T>` or an array `[T; N]` type"
-                );
-            }
-        }
-
-        false
+        self.mut_mode(&self.field.args.mut_slice, &self.struct_args.mut_slice)
+            && (self.value_ty().slice_inner_ty().is_some()
+                || self.field.args.mut_slice_path().is_some())
     }
 
-    fn slice_inner_ty(&self) -> &Type {
-        match self.field.ty.slice_inner_ty() {
+    pub fn slice_inner_ty(&self) -> &Type {
+        match self.value_ty().slice_inner_ty() {
             Some(ty) => ty,
             None => {
                 abort!(
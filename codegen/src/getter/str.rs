@@ -0,0 +1,63 @@
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, ItemFn};
+
+use crate::ty::TypeExt;
+
+use super::{gen, Context};
+
+pub fn getter(ctx: &Context) -> ItemFn {
+    let mut getter = gen::getter(ctx);
+
+    getter.sig.output = parse_quote! { -> &str };
+    getter.block = {
+        let ref_field_name = ctx.field.ref_name();
+
+        if let Some(path) = ctx.field.args.str_path() {
+            parse_quote_spanned! (ctx.field.span() => {
+                #path ( #ref_field_name )
+            })
+        } else {
+            parse_quote_spanned! (ctx.field.span() => {
+                ::core::convert::AsRef::<str>::as_ref( #ref_field_name )
+            })
+        }
+    };
+
+    getter
+}
+
+pub fn mut_getter(ctx: &Context) -> ItemFn {
+    let mut getter = gen::mut_getter(ctx);
+
+    getter.sig.output = parse_quote! { -> &mut str };
+    getter.block = {
+        let ref_mut_field_name = ctx.field.ref_mut_name();
+
+        if let Some(path) = ctx.field.args.mut_str_path() {
+            parse_quote_spanned! (ctx.field.span() => {
+                #path ( #ref_mut_field_name )
+            })
+        } else {
+            parse_quote_spanned! (ctx.field.span() => {
+                ::core::convert::AsMut::<str>::as_mut( #ref_mut_field_name )
+            })
+        }
+    };
+
+    getter
+}
+
+impl Context<'_> {
+    pub fn is_str(&self) -> bool {
+        self.value_mode(&self.field.args.str, &self.struct_args.str)
+            && (self.value_ty().is_string()
+                || self.value_ty().is_ref_string()
+                || self.field.args.str_path().is_some())
+    }
+
+    pub fn is_mut_str(&self) -> bool {
+        self.mut_mode(&self.field.args.mut_str, &self.struct_args.mut_str)
+            && (self.value_ty().is_string()
+                || self.value_ty().is_ref_string()
+                || self.field.args.mut_str_path().is_some())
+    }
+}
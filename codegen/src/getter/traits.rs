@@ -0,0 +1,128 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, DeriveInput, Type, Visibility};
+
+use crate::{args, field::Field, methods::Target};
+
+use super::{Context, StructArgs};
+
+/// Implement `Field<N>` for the field of `#[get(field = N)]`, and `FieldMut<N>` if it has a mutable getter.
+pub fn field_impls(ctx: &Context, input: &DeriveInput, target: &Target) -> TokenStream {
+    let marker = match ctx.field_marker() {
+        Some(marker) => marker,
+        None => return quote!(),
+    };
+    let krate = ctx.struct_args.crate_path();
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+    let self_ty = target.self_ty(input);
+    let ty = &ctx.field.ty;
+    let reference = ctx.field.reference();
+    let mut tokens = quote_spanned! { marker.span() =>
+        impl #impl_generics #krate::Field<#marker> for #self_ty #where_clause {
+            type Type = #ty;
+
+            #[inline(always)]
+            fn field(&self) -> &#ty {
+                #reference
+            }
+        }
+    };
+
+    if ctx.is_mutable() {
+        let mut_reference = ctx.field.mut_reference();
+
+        tokens.extend(quote_spanned! { marker.span() =>
+            impl #impl_generics #krate::FieldMut<#marker> for #self_ty #where_clause {
+                #[inline(always)]
+                fn field_mut(&mut self) -> &mut #ty {
+                    #mut_reference
+                }
+            }
+        });
+    }
+
+    tokens
+}
+
+/// The enum of `#[get(field_names)]`, which implements `FieldName` with a variant for each field.
+pub fn field_names(
+    struct_args: &StructArgs,
+    input: &DeriveInput,
+    vis: &Visibility,
+    fields: &[Field],
+) -> TokenStream {
+    let arg = match struct_args.field_names {
+        Some(ref arg) => arg,
+        None => return quote!(),
+    };
+
+    if let Some(field) = fields.iter().find(|field| field.ident.is_none()) {
+        emit_error!(
+            field.span(),
+            "#[get(field_names)] can only be applied to the structure with named fields"
+        );
+
+        return quote!();
+    }
+
+    let enum_name = arg
+        .args
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}Field", input.ident));
+    let krate = struct_args.crate_path();
+    let vis = args::vis(&None, &struct_args.vis, vis);
+    let variants = fields
+        .iter()
+        .map(|field| {
+            format_ident!(
+                "{}",
+                to_camel_case(&field.name_str()),
+                span = field.name_span()
+            )
+        })
+        .collect::<Vec<_>>();
+    let names = fields.iter().map(|field| field.name_str());
+    let doc = format!("The names of the fields of [`{}`].", input.ident);
+
+    quote_spanned! { arg.name_span =>
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #vis enum #enum_name {
+            #( #variants, )*
+        }
+
+        impl #krate::FieldName for #enum_name {
+            const ALL: &'static [Self] = &[ #( Self::#variants ),* ];
+
+            #[inline(always)]
+            fn name(self) -> &'static str {
+                match self {
+                    #( Self::#variants => #names, )*
+                }
+            }
+        }
+    }
+}
+
+impl Context<'_> {
+    pub fn field_marker(&self) -> Option<&Type> {
+        self.field.args.field.as_ref().map(|arg| &arg.value)
+    }
+}
+
+/// Convert a `snake_case` field name to `CamelCase`.
+fn to_camel_case(s: &str) -> String {
+    s.split('_')
+        .filter(|word| !word.is_empty())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
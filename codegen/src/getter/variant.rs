@@ -0,0 +1,297 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_quote, parse_quote_spanned, spanned::Spanned, Attribute, Fields, Ident, ItemFn, Pat,
+    Token, Type, Visibility,
+};
+
+use crate::args;
+
+use super::{StructArgs, VariantArgs};
+
+#[derive(Clone, Debug)]
+pub struct Variant<'a> {
+    pub struct_args: &'a StructArgs,
+    pub variant: syn::Variant,
+    pub vis: Visibility,
+    pub args: VariantArgs,
+    pub attrs: Vec<Attribute>,
+    /// The variant is the only variant of the enum.
+    pub only_variant: bool,
+}
+
+impl<'a> Variant<'a> {
+    pub fn new(
+        struct_args: &'a StructArgs,
+        vis: Visibility,
+        variant: syn::Variant,
+        only_variant: bool,
+    ) -> Self {
+        let (args, _, mut attrs) = args::extract::<VariantArgs, _>(
+            variant.attrs.clone(),
+            "get",
+            struct_args.allowed_attrs(),
+        );
+
+        if let Some(meta) = args.attr.as_ref() {
+            attrs.extend(meta.args.iter().map(|meta| {
+                parse_quote_spanned! { meta.span() =>
+                    #[ #meta ]
+                }
+            }));
+        }
+
+        Variant {
+            struct_args,
+            variant,
+            vis,
+            args,
+            attrs,
+            only_variant,
+        }
+    }
+
+    pub fn vis(&self) -> Visibility {
+        args::vis(&self.args.vis, &self.struct_args.vis, &self.vis)
+    }
+
+    pub fn constness(&self) -> Option<Token![const]> {
+        args::constness(&self.args.constness, &self.struct_args.constness)
+    }
+
+    pub fn is_mutable(&self) -> bool {
+        args::merge_bool(&self.args.mutable, &self.struct_args.mutable).unwrap_or_default()
+    }
+
+    pub fn basename(&self) -> Ident {
+        self.args
+            .rename
+            .as_ref()
+            .map(|arg| arg.args.clone())
+            .unwrap_or_else(|| {
+                Ident::new(
+                    &to_snake_case(&self.variant.ident.to_string()),
+                    self.variant.ident.span(),
+                )
+            })
+    }
+
+    pub fn method_name(&self, kind: &str) -> Ident {
+        format_ident!(
+            "{}{}_{}{}",
+            args::prefix(&self.args.prefix, &self.struct_args.prefix).unwrap_or_default(),
+            kind,
+            self.basename(),
+            args::suffix(&self.args.suffix, &self.struct_args.suffix).unwrap_or_default()
+        )
+    }
+
+    /// The pattern to match the variant, and the bindings of its fields.
+    fn pattern(&self) -> (Pat, Vec<Ident>) {
+        let ident = &self.variant.ident;
+
+        match self.variant.fields {
+            Fields::Named(ref fields) => {
+                let names = fields
+                    .named
+                    .iter()
+                    .flat_map(|field| field.ident.clone())
+                    .collect::<Vec<_>>();
+
+                (
+                    parse_quote_spanned! { self.variant.span() =>
+                        Self::#ident { #( #names ),* }
+                    },
+                    names,
+                )
+            }
+            Fields::Unnamed(ref fields) => {
+                let names = (0..fields.unnamed.len())
+                    .map(|idx| format_ident!("arg{}", idx))
+                    .collect::<Vec<_>>();
+
+                (
+                    parse_quote_spanned! { self.variant.span() =>
+                        Self::#ident ( #( #names ),* )
+                    },
+                    names,
+                )
+            }
+            Fields::Unit => (
+                parse_quote_spanned! { self.variant.span() =>
+                    Self::#ident
+                },
+                vec![],
+            ),
+        }
+    }
+
+    fn field_tys(&self) -> Vec<&Type> {
+        self.variant.fields.iter().map(|field| &field.ty).collect()
+    }
+
+    /// Pack the values as a tuple if the variant has more than one field.
+    fn pack<T: ToTokens>(&self, values: &[T]) -> TokenStream {
+        if let [value] = values {
+            value.to_token_stream()
+        } else {
+            quote! { ( #( #values ),* ) }
+        }
+    }
+
+    fn unreachable_patterns(&self) -> Option<Attribute> {
+        if self.only_variant {
+            Some(parse_quote! { #[allow(unreachable_patterns)] })
+        } else {
+            None
+        }
+    }
+
+    pub fn checker(&self) -> ItemFn {
+        let attrs = &self.attrs;
+        let vis = self.vis();
+        let constness = self.constness();
+        let method_name = self.method_name("is");
+        let ident = &self.variant.ident;
+
+        parse_quote_spanned! { self.variant.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis #constness fn #method_name( &self ) -> bool {
+                ::core::matches!(self, Self::#ident { .. })
+            }
+        }
+    }
+
+    pub fn accessor(&self) -> ItemFn {
+        let attrs = &self.attrs;
+        let allow = self.unreachable_patterns();
+        let vis = self.vis();
+        let constness = self.constness();
+        let method_name = self.method_name("as");
+        let (pattern, names) = self.pattern();
+        let ty = self.pack(
+            &self
+                .field_tys()
+                .into_iter()
+                .map(|ty| -> Type {
+                    parse_quote! { & #ty }
+                })
+                .collect::<Vec<_>>(),
+        );
+        let value = self.pack(&names);
+
+        parse_quote_spanned! { self.variant.span() =>
+            #( #attrs )*
+            #allow
+            #[inline(always)]
+            #vis #constness fn #method_name( &self ) -> ::core::option::Option<#ty> {
+                match self {
+                    #pattern => ::core::option::Option::Some(#value),
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    }
+
+    pub fn mut_accessor(&self) -> ItemFn {
+        let attrs = &self.attrs;
+        let allow = self.unreachable_patterns();
+        let vis = self.vis();
+        let method_name = format_ident!("{}_mut", self.method_name("as"));
+        let (pattern, names) = self.pattern();
+        let ty = self.pack(
+            &self
+                .field_tys()
+                .into_iter()
+                .map(|ty| -> Type {
+                    parse_quote! { &mut #ty }
+                })
+                .collect::<Vec<_>>(),
+        );
+        let value = self.pack(&names);
+
+        parse_quote_spanned! { self.variant.span() =>
+            #( #attrs )*
+            #allow
+            #[inline(always)]
+            #vis fn #method_name( &mut self ) -> ::core::option::Option<#ty> {
+                match self {
+                    #pattern => ::core::option::Option::Some(#value),
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    }
+
+    pub fn converter(&self) -> ItemFn {
+        let attrs = &self.attrs;
+        let allow = self.unreachable_patterns();
+        let vis = self.vis();
+        let method_name = self.method_name("into");
+        let (pattern, names) = self.pattern();
+        let ty = self.pack(&self.field_tys());
+        let value = self.pack(&names);
+
+        parse_quote_spanned! { self.variant.span() =>
+            #( #attrs )*
+            #allow
+            #[inline(always)]
+            #vis fn #method_name( self ) -> ::core::result::Result<#ty, Self> {
+                match self {
+                    #pattern => ::core::result::Result::Ok(#value),
+                    this => ::core::result::Result::Err(this),
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Variant<'a> {
+    pub fn accessors(&self) -> Vec<ItemFn> {
+        if self.args.skip {
+            return vec![];
+        }
+
+        let mut accessors = vec![self.checker()];
+
+        if self.variant.fields.is_empty() {
+            return accessors;
+        }
+
+        accessors.push(self.accessor());
+
+        if self.is_mutable() {
+            accessors.push(self.mut_accessor());
+        }
+
+        accessors.push(self.converter());
+        accessors
+    }
+}
+
+/// Convert a `CamelCase` variant name to `snake_case`.
+fn to_snake_case(s: &str) -> String {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut name = String::with_capacity(s.len() + 4);
+
+    for (idx, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = idx.checked_sub(1).and_then(|idx| chars.get(idx));
+            let next = chars.get(idx + 1);
+
+            if prev.map_or(false, |&c| c.is_lowercase() || c.is_ascii_digit())
+                || (prev.map_or(false, |&c| c.is_uppercase())
+                    && next.map_or(false, |&c| c.is_lowercase()))
+            {
+                name.push('_');
+            }
+
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+
+    name
+}
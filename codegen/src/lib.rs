@@ -0,0 +1,133 @@
+//! The code generator of [`getset2`](https://docs.rs/getset2), which expands the getters, setters and builders
+//! from a [`syn::DeriveInput`], so the other derives can reuse it instead of copying it.
+//!
+//! The errors are reported with [`proc_macro_error`], so the expansions should be called in a function
+//! with the `#[proc_macro_error]` attribute.
+//! The generated code doesn't depend on the `getset2` crate, except the field traits of `#[get(field = ..)]`,
+//! the enum of `#[get(field_names)]` and the `try_into` setters of `#[set(error = wrap)]`,
+//! which refer to `::getset2` unless another path is given with `crate = ...`.
+//!
+//! ```ignore
+//! use proc_macro::TokenStream;
+//! use proc_macro_error::proc_macro_error;
+//! use syn::{parse_macro_input, DeriveInput};
+//!
+//! #[proc_macro_derive(Model, attributes(get, set))]
+//! #[proc_macro_error]
+//! pub fn model(input: TokenStream) -> TokenStream {
+//!     let input = parse_macro_input!(input as DeriveInput);
+//!     let mut options = getset2_codegen::Options::default();
+//!
+//!     // read `getset2.toml` like the derives of getset2
+//!     options.config = true;
+//!
+//!     let mut expanded = getset2_codegen::expand_getter(input.clone(), &options);
+//!
+//!     expanded.extend(getset2_codegen::expand_setter(input, &options));
+//!     expanded.into()
+//! }
+//! ```
+
+use proc_macro2::TokenStream;
+use syn::{Attribute, DeriveInput};
+
+use crate::methods::Target;
+
+mod accessors;
+mod args;
+mod builder;
+mod compat;
+mod config;
+mod field;
+mod foreign;
+mod getter;
+mod methods;
+mod options;
+mod path;
+mod setter;
+mod ty;
+mod vis;
+
+pub use self::accessors::Args as AccessorsArgs;
+pub use self::args::AttrArgs;
+pub use self::builder::StructArgs as BuilderStructArgs;
+pub use self::compat::Kind as CompatKind;
+pub use self::foreign::Accessors;
+pub use self::getter::{FieldArgs as GetterFieldArgs, StructArgs as GetterStructArgs};
+pub use self::options::Options;
+pub use self::setter::{FieldArgs as SetterFieldArgs, StructArgs as SetterStructArgs};
+pub use self::ty::TypeExt;
+
+/// Parse the arguments of a structure like `#[get(..)]`, which are layered over the defaults of the options.
+///
+/// The invalid arguments are reported with [`proc_macro_error`] and skipped.
+pub fn extract_struct_args<T: AttrArgs>(attrs: &[Attribute], options: &Options) -> T {
+    args::extract_struct::<T, _>(attrs.iter().cloned(), T::ATTR, options).0
+}
+
+/// Parse the arguments of a field like `#[get(..)]`.
+///
+/// The invalid arguments are reported with [`proc_macro_error`] and skipped.
+pub fn extract_field_args<T: AttrArgs>(attrs: &[Attribute]) -> T {
+    args::extract::<T, _>(attrs.iter().cloned(), T::ATTR, None).0
+}
+
+/// Expand `#[derive(Getter)]`.
+pub fn expand_getter(mut input: DeriveInput, options: &Options) -> TokenStream {
+    if options.compat {
+        compat::translate(&mut input, compat::Kind::GETTERS);
+    }
+
+    let mut tokens = getter::expand(input, options, &Target::default());
+
+    tokens.extend(options.track());
+    tokens
+}
+
+/// Expand `#[derive(Setter)]`.
+pub fn expand_setter(mut input: DeriveInput, options: &Options) -> TokenStream {
+    if options.compat {
+        compat::translate(&mut input, compat::Kind::SETTERS);
+    }
+
+    let mut tokens = setter::expand(input, options, &Target::default());
+
+    tokens.extend(options.track());
+    tokens
+}
+
+/// Expand `#[derive(Builder)]`.
+pub fn expand_builder(mut input: DeriveInput, options: &Options) -> TokenStream {
+    if options.compat {
+        compat::translate(&mut input, compat::Kind::SETTERS);
+    }
+
+    let mut tokens = builder::expand(input, options);
+
+    tokens.extend(options.track());
+    tokens
+}
+
+/// Expand the derives of the original `getset` crate, like `#[derive(CopyGetters)]` for [`CompatKind::GetCopy`].
+pub fn expand_compat(input: DeriveInput, kind: CompatKind, options: &Options) -> TokenStream {
+    let mut tokens = compat::expand(input, kind, options);
+
+    tokens.extend(options.track());
+    tokens
+}
+
+/// Expand the `#[accessors(..)]` attribute, which also rewrites the structure.
+pub fn expand_accessors(args: AccessorsArgs, input: DeriveInput, options: &Options) -> TokenStream {
+    let mut tokens = accessors::expand(args, input, options);
+
+    tokens.extend(options.track());
+    tokens
+}
+
+/// Expand the `impl_accessors! { .. }` macro.
+pub fn expand_impl_accessors(accessors: Accessors, options: &Options) -> TokenStream {
+    let mut tokens = foreign::expand(accessors, options);
+
+    tokens.extend(options.track());
+    tokens
+}
@@ -0,0 +1,345 @@
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use proc_macro_error::emit_error;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, DeriveInput, Expr, FnArg, Ident, ItemFn, Path,
+    ReturnType, Signature, Stmt, TraitItemFn, Type, TypeImplTrait, TypePath, Visibility,
+};
+
+/// The target of the generated methods, which differs from the derived structure for the other entry points.
+#[derive(Clone, Debug, Default)]
+pub struct Target {
+    /// The type implementing the methods instead of the structure, like `pb::Request` of `impl_accessors!`.
+    pub self_ty: Option<TypePath>,
+    /// Only generate the methods which borrow `self` mutably or not, like `MutGetters` and `Getters` of `getset`.
+    pub mutable: Option<bool>,
+}
+
+impl Target {
+    pub fn self_ty(&self, input: &DeriveInput) -> TokenStream {
+        match self.self_ty {
+            Some(ref self_ty) => self_ty.to_token_stream(),
+            None => {
+                let ident = &input.ident;
+                let (_, ty_generics, _) = input.generics.split_for_impl();
+
+                quote! { #ident #ty_generics }
+            }
+        }
+    }
+
+    pub fn retain(&self, methods: Vec<ItemFn>) -> Vec<ItemFn> {
+        match self.mutable {
+            Some(mutable) => methods
+                .into_iter()
+                .filter(|method| borrows_mut(&method.sig) == mutable)
+                .collect(),
+            None => methods,
+        }
+    }
+}
+
+/// The trait declared with the generated methods, like `#[get(trait = Name)]`.
+pub struct TraitDecl<'a> {
+    pub name: &'a Ident,
+    pub vis: Visibility,
+    pub doc: &'static str,
+    /// The methods which return `Self` like `&mut Self` return the trait object instead.
+    pub dyn_trait: bool,
+}
+
+/// Place the generated methods in an inherent `impl` block, or in a trait and its implementation.
+pub fn expand(
+    input: &DeriveInput,
+    target: &Target,
+    trait_decl: Option<TraitDecl>,
+    alloc_crate: Ident,
+    methods: Vec<ItemFn>,
+) -> TokenStream {
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let self_ty = target.self_ty(input);
+    let methods = target.retain(methods);
+
+    let TraitDecl {
+        name: trait_name,
+        vis: trait_vis,
+        doc: trait_doc,
+        dyn_trait,
+    } = match trait_decl {
+        Some(trait_decl) => trait_decl,
+        None => {
+            return quote_spanned! { input.span() =>
+                impl #impl_generics #self_ty #where_clause {
+                    #( #methods )*
+                }
+            };
+        }
+    };
+
+    let dyn_self: Option<Type> = if dyn_trait {
+        Some(parse_quote! { dyn #trait_name #ty_generics })
+    } else {
+        None
+    };
+    let methods = methods
+        .into_iter()
+        .map(|method| trait_method(method, &alloc_crate, dyn_self.as_ref()))
+        .collect::<Vec<_>>();
+    let decls = methods.iter().map(|method| {
+        let mut sig = method.sig.clone();
+
+        // patterns like `mut self` aren't allowed in functions without bodies
+        if let Some(FnArg::Receiver(receiver)) = sig.inputs.first_mut() {
+            if receiver.reference.is_none() {
+                receiver.mutability = None;
+            }
+        }
+
+        TraitItemFn {
+            attrs: method
+                .attrs
+                .iter()
+                .filter(|attr| !attr.path().is_ident("inline"))
+                .cloned()
+                .collect(),
+            sig,
+            default: None,
+            semi_token: Some(Default::default()),
+        }
+    });
+    let doc = format!(
+        "{} [`{}`].",
+        trait_doc,
+        self_ty.to_string().replace(' ', "")
+    );
+
+    quote_spanned! { input.span() =>
+        #[doc = #doc]
+        #trait_vis trait #trait_name #generics #where_clause {
+            #( #decls )*
+        }
+
+        impl #impl_generics #trait_name #ty_generics for #self_ty #where_clause {
+            #( #methods )*
+        }
+    }
+}
+
+/// Replace the `#[inline(..)]` attributes of the methods with the policy of `inline(..)` if any.
+pub fn inline(methods: Vec<ItemFn>, inline: &Option<Vec<Attribute>>) -> Vec<ItemFn> {
+    let inline = match inline {
+        Some(inline) => inline,
+        None => return methods,
+    };
+
+    methods
+        .into_iter()
+        .map(|mut method| {
+            method.attrs.retain(|attr| !attr.path().is_ident("inline"));
+            method.attrs.extend(inline.iter().cloned());
+            method
+        })
+        .collect()
+}
+
+/// The methods which implement the existing traits, grouped by the path of trait.
+#[derive(Default)]
+pub struct TraitImpls(Vec<(Path, Vec<ItemFn>)>);
+
+impl TraitImpls {
+    pub fn push(&mut self, path: &Path, methods: Vec<ItemFn>) {
+        let key = path.to_token_stream().to_string();
+
+        match self
+            .0
+            .iter_mut()
+            .find(|(path, _)| path.to_token_stream().to_string() == key)
+        {
+            Some((_, impl_methods)) => impl_methods.extend(methods),
+            None => self.0.push((path.clone(), methods)),
+        }
+    }
+
+    /// Place the methods in `impl Trait for ...` blocks, the visibility and constness are removed.
+    pub fn expand(
+        self,
+        input: &DeriveInput,
+        target: &Target,
+        inline_attrs: &Option<Vec<Attribute>>,
+    ) -> TokenStream {
+        let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+        let self_ty = target.self_ty(input);
+
+        self.0
+            .into_iter()
+            .filter_map(|(path, methods)| {
+                let methods = target.retain(inline(methods, inline_attrs));
+
+                if methods.is_empty() {
+                    return None;
+                }
+
+                let methods = methods.into_iter().map(|mut method| {
+                    method.vis = Visibility::Inherited;
+                    method.sig.constness = None;
+                    method
+                });
+
+                Some(quote_spanned! { path.span() =>
+                    impl #impl_generics #path for #self_ty #where_clause {
+                        #( #methods )*
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+/// The names of the generated methods and the origins like fields, to detect the collisions.
+pub struct MethodNames {
+    derive: &'static str,
+    names: Vec<(String, usize, Span)>,
+    origins: usize,
+}
+
+impl MethodNames {
+    pub fn new(derive: &'static str) -> Self {
+        MethodNames {
+            derive,
+            names: vec![],
+            origins: 0,
+        }
+    }
+
+    /// Record the methods generated for an origin, the colliding methods are reported and removed.
+    pub fn insert(&mut self, span: Span, methods: Vec<ItemFn>) -> Vec<ItemFn> {
+        let origin = self.origins;
+
+        self.origins += 1;
+
+        methods
+            .into_iter()
+            .filter(|method| {
+                let name = method.sig.ident.to_string();
+                let help = "use `rename`, `prefix` or `suffix` to generate a different name";
+
+                match self.names.iter().find(|(other, _, _)| *other == name) {
+                    Some(&(_, other_origin, other_span)) => {
+                        if other_origin != origin {
+                            emit_error!(
+                                other_span,
+                                "#[derive({})] generates the method `{}` more than once", self.derive, name;
+                                help = help
+                            );
+                        }
+                        emit_error!(
+                            span,
+                            "#[derive({})] generates the method `{}` more than once", self.derive, name;
+                            help = help
+                        );
+
+                        false
+                    }
+                    None => {
+                        self.names.push((name, origin, span));
+
+                        true
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Convert an inherent method to a trait method, which keeps the trait object safe.
+///
+/// - the visibility and constness are removed
+/// - the `impl Trait` result is boxed as `Box<dyn Trait>`
+/// - the `Self` result of the borrowed methods is replaced with `dyn_self` if any
+/// - the generic or owned methods, or the methods which return `Self` like `&mut Self`,
+///   are bounded with `where Self: Sized`
+fn trait_method(mut method: ItemFn, alloc_crate: &Ident, dyn_self: Option<&Type>) -> ItemFn {
+    method.vis = Visibility::Inherited;
+    method.sig.constness = None;
+
+    if let ReturnType::Type(_, ref mut ty) = method.sig.output {
+        if let Type::ImplTrait(TypeImplTrait { ref bounds, .. }) = **ty {
+            let body: Expr = match method.block.stmts.as_slice() {
+                [Stmt::Expr(expr, None)] => expr.clone(),
+                _ => {
+                    let block = &method.block;
+
+                    parse_quote! { #block }
+                }
+            };
+
+            **ty = parse_quote! { ::#alloc_crate::boxed::Box<dyn #bounds> };
+            method.block = parse_quote! {{
+                ::#alloc_crate::boxed::Box::new(#body)
+            }};
+        }
+    }
+
+    let is_owned = method.sig.inputs.first().map_or(true, |arg| match arg {
+        FnArg::Receiver(receiver) => receiver.reference.is_none(),
+        FnArg::Typed(_) => true,
+    });
+
+    if let Some(dyn_self) = dyn_self {
+        if !is_owned && method.sig.generics.params.is_empty() {
+            if let ReturnType::Type(_, ref mut ty) = method.sig.output {
+                **ty = syn::parse2(replace_self(ty.to_token_stream(), dyn_self))
+                    .expect("the result type is still a type");
+            }
+        }
+    }
+
+    if is_owned
+        || !method.sig.generics.params.is_empty()
+        || mentions_self(method.sig.output.to_token_stream())
+    {
+        method
+            .sig
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { Self: ::core::marker::Sized });
+    }
+
+    method
+}
+
+fn replace_self(tokens: TokenStream, dyn_self: &Type) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ref ident) if ident == "Self" => {
+                Group::new(Delimiter::Parenthesis, dyn_self.to_token_stream()).into()
+            }
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self(group.stream(), dyn_self));
+
+                replaced.set_span(group.span());
+                replaced.into()
+            }
+            token => token,
+        })
+        .collect()
+}
+
+fn borrows_mut(sig: &Signature) -> bool {
+    matches!(
+        sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_some()
+    )
+}
+
+fn mentions_self(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "Self",
+        TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
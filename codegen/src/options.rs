@@ -0,0 +1,64 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::emit_error;
+use syn::parse::Parse;
+
+use crate::config;
+
+/// The options of the code generator, which are shared by the expansions.
+///
+/// More options may be added in the minor versions, so it should be created with [`Options::default`].
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct Options {
+    /// Read the crate-wide defaults from `getset2.toml` or `[package.metadata.getset2]` of `Cargo.toml`, `false` by default.
+    pub config: bool,
+    /// Understand the attributes of the original `getset` crate like `#[get = "pub"]`, `false` by default.
+    pub compat: bool,
+    /// The arguments of `#[get(..)]` below the crate-wide defaults, like `pub(crate), prefix = "get"`.
+    pub get_defaults: Option<TokenStream>,
+    /// The arguments of `#[set(..)]` below the crate-wide defaults.
+    pub set_defaults: Option<TokenStream>,
+    /// The arguments of `#[builder(..)]` below the crate-wide defaults.
+    pub builder_defaults: Option<TokenStream>,
+}
+
+impl Options {
+    /// The layers of defaults for the arguments of the structure, the crate-wide defaults take precedence.
+    pub(crate) fn defaults<T: Parse>(&self, name: &str) -> Vec<T> {
+        let mut layers = vec![];
+
+        if self.config {
+            layers.extend(config::defaults(name));
+        }
+
+        let defaults = match name {
+            "get" => self.get_defaults.as_ref(),
+            "set" => self.set_defaults.as_ref(),
+            "builder" => self.builder_defaults.as_ref(),
+            _ => None,
+        };
+
+        if let Some(tokens) = defaults {
+            match syn::parse2(tokens.clone()) {
+                Ok(args) => layers.push(args),
+                Err(err) => emit_error!(
+                    Span::call_site(),
+                    "invalid default arguments of #[{}(..)], {}",
+                    name,
+                    err
+                ),
+            }
+        }
+
+        layers
+    }
+
+    /// Depend on the crate-wide defaults, so the crate is rebuilt when they are changed.
+    pub(crate) fn track(&self) -> TokenStream {
+        if self.config {
+            config::track()
+        } else {
+            TokenStream::new()
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use structmeta::NameArgs;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote, Ident, Member, Token, Type,
+};
+
+use crate::args::Modes;
+
+/// A path to a nested field like `net.http.timeout`, the `?` marks an `Option` link like `auth?.token`.
+#[derive(Clone, Debug)]
+pub struct FieldPath {
+    pub segments: Vec<Segment>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Segment {
+    pub member: Member,
+    pub optional: bool,
+}
+
+impl Parse for FieldPath {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut segments = vec![];
+
+        loop {
+            let member = input.parse()?;
+            let optional = input.parse::<Option<Token![?]>>()?.is_some();
+
+            segments.push(Segment { member, optional });
+
+            if input.parse::<Option<Token![.]>>()?.is_none() {
+                break;
+            }
+        }
+
+        Ok(FieldPath { segments })
+    }
+}
+
+impl FieldPath {
+    /// Whether the path goes through any `Option` link.
+    pub fn is_optional(&self) -> bool {
+        self.segments.iter().any(|segment| segment.optional)
+    }
+
+    /// The reference to the nested field, `&T` or `Option<&T>` if the path goes through any `Option` link.
+    pub fn reference(&self) -> TokenStream {
+        let mut expr = quote! { self };
+        let mut optional = false;
+
+        for Segment {
+            member,
+            optional: link,
+        } in &self.segments
+        {
+            expr = match (optional, link) {
+                (false, false) => quote! { #expr.#member },
+                (false, true) => quote! { #expr.#member.as_ref() },
+                (true, false) => quote! { #expr.map(|v| &v.#member) },
+                (true, true) => quote! { #expr.and_then(|v| v.#member.as_ref()) },
+            };
+            optional |= link;
+        }
+
+        if optional {
+            expr
+        } else {
+            quote! { &#expr }
+        }
+    }
+
+    /// The place of the nested field, the `None` links are initialized with the default value before writing,
+    /// so the types behind the `Option` links should implement `Default`.
+    pub fn place_mut(&self) -> TokenStream {
+        self.segments
+            .iter()
+            .fold(quote! { self }, |expr, Segment { member, optional }| {
+                if *optional {
+                    quote! { #expr.#member.get_or_insert_with(::core::default::Default::default) }
+                } else {
+                    quote! { #expr.#member }
+                }
+            })
+    }
+}
+
+/// A method reading or writing through a field path, like `timeout: net.http.timeout as Duration, copy`.
+#[derive(Clone, Debug)]
+pub struct PathMethod {
+    pub name: Ident,
+    pub path: FieldPath,
+    pub ty: Option<Type>,
+    pub modes: Modes,
+}
+
+impl PathMethod {
+    /// The type of the nested field, which is required unless it could be inferred from the mode,
+    /// because the types of the nested structures are unknown to the derive macros.
+    pub fn ty(&self, attr: &str) -> Option<Type> {
+        match self.ty {
+            Some(ref ty) => Some(ty.clone()),
+            None if self.modes.has("str") => Some(parse_quote! { str }),
+            None => {
+                emit_error!(
+                    self.name.span(),
+                    "the type of `{}` can't be inferred from the path", self.name;
+                    note = "the derive macros can't see the fields of the nested structures";
+                    help = "specify the type like #[{}(path({}: ... as Type))]", attr, self.name
+                );
+
+                None
+            }
+        }
+    }
+}
+
+/// The methods of `path(...)`, the modes follow the method like `path(a: x.y as u8, copy, b: x.z, str)`.
+#[derive(Clone, Debug)]
+pub struct Paths(pub Vec<PathMethod>);
+
+impl Parse for Paths {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut methods: Vec<PathMethod> = vec![];
+
+        while !input.is_empty() {
+            let name: Ident = input.parse()?;
+
+            if input.peek(Token![:]) {
+                input.parse::<Token![:]>()?;
+                let path = input.parse()?;
+                let ty = if input.parse::<Option<Token![as]>>()?.is_some() {
+                    Some(input.parse()?)
+                } else {
+                    None
+                };
+
+                methods.push(PathMethod {
+                    name,
+                    path,
+                    ty,
+                    modes: Modes::default(),
+                });
+            } else {
+                match methods.last_mut() {
+                    Some(method) => method.modes.0.push(name),
+                    None => return Err(input.error("expected `name: path`")),
+                }
+            }
+
+            if input.parse::<Option<Token![,]>>()?.is_none() {
+                break;
+            }
+        }
+
+        Ok(Paths(methods))
+    }
+}
+
+pub fn merge_paths(lhs: &mut Option<NameArgs<Paths>>, rhs: Option<NameArgs<Paths>>) {
+    if let Some(lhs) = lhs {
+        if let Some(rhs) = rhs {
+            lhs.args.0.extend(rhs.args.0);
+        }
+    } else {
+        *lhs = rhs
+    }
+}
@@ -0,0 +1,262 @@
+use merge::Merge;
+use structmeta::{Flag, NameArgs, NameValue, StructMeta};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    Expr, Ident, LitBool, LitStr, Meta, Path, Token, Type, TypeParam,
+};
+
+use crate::{
+    args::{self, merge_flag, merge_name_args, ArgNames, Flatten, Toggle},
+    path::{merge_paths, PathMethod, Paths},
+    vis::Restricted,
+};
+
+#[derive(Clone, Debug, Default, Merge, StructMeta)]
+pub struct StructArgs {
+    #[struct_meta(name = "pub")]
+    pub vis: Option<NameArgs<Option<Restricted>>>,
+    #[merge(strategy = merge_flag)]
+    pub into: Flag,
+    #[merge(strategy = merge_flag)]
+    pub try_into: Flag,
+    pub opt: Option<NameArgs<Option<Toggle<OptArgs>>>>,
+    #[merge(strategy = merge_flag)]
+    pub owned: Flag,
+    #[merge(strategy = merge_flag)]
+    pub collection: Flag,
+    pub prefix: Option<NameValue<LitStr>>,
+    pub suffix: Option<NameValue<LitStr>>,
+    pub inline: Option<NameArgs<Option<Toggle<Ident>>>>,
+    #[merge(strategy = merge_name_args)]
+    pub attrs: Option<NameArgs<Vec<LitStr>>>,
+    #[struct_meta(name = "trait")]
+    pub trait_name: Option<NameValue<Ident>>,
+    #[struct_meta(name = "dyn")]
+    #[merge(strategy = merge_flag)]
+    pub dyn_trait: Flag,
+    #[merge(strategy = merge_paths)]
+    pub path: Option<NameArgs<Paths>>,
+    #[merge(strategy = merge_flag)]
+    pub no_std: Flag,
+    pub error: Option<NameValue<Ident>>,
+    #[struct_meta(name = "crate")]
+    pub krate: Option<NameValue<Path>>,
+}
+
+impl StructArgs {
+    /// Whether `error = wrap` wraps the errors of the `try_into` setters in `SetError`.
+    pub fn wrap_error(&self) -> bool {
+        self.error.as_ref().map_or(false, |arg| arg.value == "wrap")
+    }
+
+    /// The path of the `getset2` crate, which provides the `SetError` of `error = wrap`.
+    pub fn crate_path(&self) -> Path {
+        args::crate_path(&self.krate)
+    }
+
+    pub fn trait_name(&self) -> Option<&Ident> {
+        self.trait_name.as_ref().map(|arg| &arg.value)
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &PathMethod> {
+        self.path.iter().flat_map(|arg| arg.args.0.iter())
+    }
+
+    pub fn allowed_attrs(&self) -> Option<Vec<String>> {
+        self.attrs
+            .as_ref()
+            .map(|arg| arg.args.iter().map(|s| s.value()).collect())
+    }
+}
+
+#[derive(Clone, Debug, Default, Merge, StructMeta)]
+pub struct FieldArgs {
+    #[struct_meta(name = "pub")]
+    pub vis: Option<NameArgs<Option<Restricted>>>,
+    #[merge(strategy = merge::bool::overwrite_false)]
+    pub skip: bool,
+    pub into: Option<NameArgs<Option<LitBool>>>,
+    pub try_into: Option<NameArgs<Option<LitBool>>>,
+    pub opt: Option<NameArgs<Option<Toggle<OptArgs>>>>,
+    pub extend: Option<NameArgs<Option<ExtendArgs>>>,
+    pub owned: Option<NameArgs<Option<LitBool>>>,
+    pub collection: Option<NameArgs<Option<Toggle<CollectionType>>>>,
+    pub default: Option<NameValue<Expr>>,
+    pub flatten: Option<NameArgs<Flatten>>,
+    pub rename: Option<NameArgs<Ident>>,
+    pub prefix: Option<NameValue<LitStr>>,
+    pub suffix: Option<NameValue<LitStr>>,
+    #[merge(strategy = merge_name_args)]
+    pub attr: Option<NameArgs<Vec<Meta>>>,
+}
+
+impl ArgNames for StructArgs {
+    const NAMES: &'static [&'static str] = &[
+        "pub",
+        "into",
+        "try_into",
+        "opt",
+        "owned",
+        "collection",
+        "prefix",
+        "suffix",
+        "inline",
+        "attrs",
+        "trait",
+        "dyn",
+        "path",
+        "no_std",
+        "error",
+        "crate",
+    ];
+    const OTHER_LEVEL: Option<(&'static str, &'static [&'static str])> =
+        Some(("field", FieldArgs::NAMES));
+}
+
+impl ArgNames for FieldArgs {
+    const NAMES: &'static [&'static str] = &[
+        "pub",
+        "skip",
+        "into",
+        "try_into",
+        "opt",
+        "extend",
+        "owned",
+        "collection",
+        "default",
+        "flatten",
+        "rename",
+        "prefix",
+        "suffix",
+        "attr",
+    ];
+    const OTHER_LEVEL: Option<(&'static str, &'static [&'static str])> =
+        Some(("structure", StructArgs::NAMES));
+}
+
+#[derive(Clone, Debug, Default, StructMeta)]
+pub struct OptArgs {
+    pub take: Flag,
+    pub replace: Flag,
+    pub clear: Flag,
+}
+
+impl FieldArgs {
+    pub fn extend(&self) -> Option<&Extend> {
+        self.extend
+            .as_ref()
+            .and_then(|arg| arg.args.as_ref())
+            .and_then(|args| args.item.as_ref())
+    }
+
+    /// Whether `#[set(extend(only))]` suppresses the plain setter.
+    pub fn is_extend_only(&self) -> bool {
+        self.extend
+            .as_ref()
+            .and_then(|arg| arg.args.as_ref())
+            .map_or(false, |args| args.only.is_some())
+    }
+
+    pub fn default_value(&self) -> Option<&Expr> {
+        self.default.as_ref().map(|arg| &arg.value)
+    }
+
+    pub fn collection_ty(&self) -> Option<&CollectionType> {
+        self.collection
+            .as_ref()
+            .and_then(|arg| arg.args.as_ref())
+            .and_then(|toggle| toggle.args())
+    }
+}
+
+/// The arguments of `extend(...)`, like `extend(Item)`, `extend(only)` or `extend(Item, only)`.
+#[derive(Clone, Debug)]
+pub struct ExtendArgs {
+    pub item: Option<Extend>,
+    pub only: Option<Ident>,
+}
+
+impl Parse for ExtendArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let is_only = |input: ParseStream| {
+            let fork = input.fork();
+
+            fork.parse::<Ident>().map_or(false, |ident| ident == "only") && fork.is_empty()
+        };
+
+        if is_only(input) {
+            return Ok(ExtendArgs {
+                item: None,
+                only: Some(input.parse()?),
+            });
+        }
+
+        let item = input.parse()?;
+        let only = if input.parse::<Option<Token![,]>>()?.is_some() {
+            let ident: Ident = input.parse()?;
+
+            if ident != "only" {
+                return Err(syn::Error::new(ident.span(), "expected `only`"));
+            }
+
+            Some(ident)
+        } else {
+            None
+        };
+
+        Ok(ExtendArgs {
+            item: Some(item),
+            only,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Extend {
+    Type(Type),
+
+    Bound(TypeParam),
+}
+
+impl Parse for Extend {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(Token![:]) {
+            input.parse().map(Extend::Bound)
+        } else {
+            input.parse().map(Extend::Type)
+        }
+    }
+}
+
+/// The item types of a custom collection, like `seq(T)`, `set(T)` or `map(K, V)`.
+#[derive(Clone, Debug)]
+pub enum CollectionType {
+    Seq(Type),
+    Set(Type),
+    Map(Box<Type>, Box<Type>),
+}
+
+impl Parse for CollectionType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kind: Ident = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+
+        match kind.to_string().as_str() {
+            "seq" => content.parse().map(CollectionType::Seq),
+            "set" => content.parse().map(CollectionType::Set),
+            "map" => {
+                let key_ty = content.parse()?;
+                content.parse::<Token![,]>()?;
+                let value_ty = content.parse()?;
+
+                Ok(CollectionType::Map(Box::new(key_ty), Box::new(value_ty)))
+            }
+            _ => Err(syn::Error::new(
+                kind.span(),
+                "expected `seq(T)`, `set(T)` or `map(K, V)`",
+            )),
+        }
+    }
+}
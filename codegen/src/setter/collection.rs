@@ -0,0 +1,296 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::{parse_quote_spanned, spanned::Spanned, Ident, ItemFn, Type};
+
+use crate::{args, ty};
+
+use super::{args::CollectionType, Context};
+
+/// A collection type, which is one of the well-known types or specified with `#[set(collection(...))]`.
+pub struct Collection {
+    /// The name of the well-known collection type, `None` for a custom collection.
+    pub name: Option<&'static str>,
+    pub ty: CollectionType,
+    /// The hasher `S` of a `HashMap<K, V, S>` or `HashSet<T, S>`.
+    pub hasher: Option<Type>,
+    /// The type is named like `HashMap` or `std::collections::HashMap`, rather than a type of another crate.
+    pub is_std: bool,
+}
+
+impl Collection {
+    fn is(&self, names: &[&str]) -> bool {
+        self.name.map_or(false, |name| names.contains(&name))
+    }
+
+    /// The bounds of the key and the hasher for a well-known set or map.
+    fn key_bounds(&self, key_ty: &Type) -> Option<TokenStream> {
+        self.name.map(|name| {
+            let bounds = ty::lookup_bounds(name);
+            let hasher_bounds = self.hasher_bounds();
+
+            quote! { #key_ty: #bounds, #hasher_bounds }
+        })
+    }
+
+    /// The hasher of a `HashMap<K, V, S>` may be a generic parameter, which should be bounded to lookup the keys.
+    fn hasher_bounds(&self) -> Option<TokenStream> {
+        self.hasher
+            .as_ref()
+            .map(|hasher| quote! { #hasher: ::core::hash::BuildHasher })
+    }
+
+    /// The `Entry` type of a well-known map, `BTreeMap` is also available in the `alloc` crate.
+    ///
+    /// A map of another crate like `hashbrown::HashMap` has its own `Entry` type, so it has no `_entry` setter.
+    fn entry_ty(&self, alloc_crate: &Ident) -> Option<TokenStream> {
+        if !self.is_std {
+            return None;
+        }
+
+        match self.name {
+            Some("HashMap") => Some(quote! { ::std::collections::hash_map::Entry }),
+            Some("BTreeMap") => Some(quote! { ::#alloc_crate::collections::btree_map::Entry }),
+            _ => None,
+        }
+    }
+
+    /// The generic lookup key of a well-known set or map, or the key itself for a custom collection.
+    fn lookup(&self, key_ty: &Type) -> (Option<TokenStream>, TokenStream, Option<TokenStream>) {
+        match self.name {
+            Some(name) => {
+                let bounds = ty::lookup_bounds(name);
+                let hasher_bounds = self.hasher_bounds();
+
+                (
+                    Some(quote! { <Q> }),
+                    quote! { &Q },
+                    Some(quote! {
+                        where
+                            #key_ty: ::core::borrow::Borrow<Q> + #bounds,
+                            Q: #bounds + ?Sized,
+                            #hasher_bounds
+                    }),
+                )
+            }
+            None => (None, quote! { &#key_ty }, None),
+        }
+    }
+}
+
+pub fn setters(ctx: &Context) -> Vec<ItemFn> {
+    let collection = ctx.collection();
+    let attrs = &ctx.field.attrs;
+    let vis = ctx.vis();
+    let basename = ctx.field.basename();
+    let suffix = ctx.suffix();
+    let place = ctx.field.place_mut();
+    let method_name =
+        |kind: &str| -> Ident { format_ident!("{}{}{}", ctx.with_prefix(kind), basename, suffix) };
+    let mut setters: Vec<ItemFn> = vec![];
+
+    match collection.ty {
+        CollectionType::Seq(ref item_ty) => {
+            let push_name = method_name("push");
+            let pop_name = method_name("pop");
+            let (push, pop) = if collection.is(&["VecDeque", "LinkedList"]) {
+                (quote! { push_back }, quote! { pop_back })
+            } else {
+                (quote! { push }, quote! { pop })
+            };
+
+            setters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #push_name(&mut self, value: #item_ty) -> &mut Self {
+                    #place.#push(value);
+                    self
+                }
+            });
+
+            setters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #pop_name(&mut self) -> ::core::option::Option<#item_ty> {
+                    #place.#pop()
+                }
+            });
+
+            if !collection.is(&["LinkedList", "BinaryHeap"]) {
+                let retain_name = method_name("retain");
+
+                setters.push(parse_quote_spanned! { ctx.field.span() =>
+                    #( #attrs )*
+                    #[inline(always)]
+                    #vis fn #retain_name<F>(&mut self, f: F) -> &mut Self
+                    where
+                        F: ::core::ops::FnMut(&#item_ty) -> bool,
+                    {
+                        #place.retain(f);
+                        self
+                    }
+                });
+            }
+        }
+        CollectionType::Set(ref item_ty) => {
+            let insert_name = method_name("insert");
+            let remove_name = method_name("remove");
+            let retain_name = method_name("retain");
+            let bounds = collection.key_bounds(item_ty).map(|bounds| {
+                quote! { where #bounds }
+            });
+            let (generics, lookup_ty, where_clause) = collection.lookup(item_ty);
+
+            setters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #insert_name(&mut self, value: #item_ty) -> bool #bounds {
+                    #place.insert(value)
+                }
+            });
+
+            setters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #remove_name #generics (&mut self, value: #lookup_ty) -> bool #where_clause {
+                    #place.remove(value)
+                }
+            });
+
+            setters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #retain_name<F>(&mut self, f: F) -> &mut Self
+                where
+                    F: ::core::ops::FnMut(&#item_ty) -> bool,
+                {
+                    #place.retain(f);
+                    self
+                }
+            });
+        }
+        CollectionType::Map(ref key_ty, ref value_ty) => {
+            let insert_name = method_name("insert");
+            let remove_name = method_name("remove");
+            let retain_name = method_name("retain");
+            let bounds = collection.key_bounds(key_ty).map(|bounds| {
+                quote! { where #bounds }
+            });
+            let (generics, lookup_ty, where_clause) = collection.lookup(key_ty);
+
+            setters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #insert_name(&mut self, key: #key_ty, value: #value_ty) -> ::core::option::Option<#value_ty> #bounds {
+                    #place.insert(key, value)
+                }
+            });
+
+            setters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #remove_name #generics (&mut self, key: #lookup_ty) -> ::core::option::Option<#value_ty> #where_clause {
+                    #place.remove(key)
+                }
+            });
+
+            if let Some(entry_ty) =
+                collection.entry_ty(&args::alloc_crate(ctx.struct_args.no_std.value()))
+            {
+                let entry_name = format_ident!(
+                    "{}{}{}_entry",
+                    ctx.prefix_arg().unwrap_or_default(),
+                    basename,
+                    suffix
+                );
+
+                setters.push(parse_quote_spanned! { ctx.field.span() =>
+                    #( #attrs )*
+                    #[inline(always)]
+                    #vis fn #entry_name(&mut self, key: #key_ty) -> #entry_ty<'_, #key_ty, #value_ty> #bounds {
+                        #place.entry(key)
+                    }
+                });
+            }
+
+            setters.push(parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #retain_name<F>(&mut self, f: F) -> &mut Self
+                where
+                    F: ::core::ops::FnMut(&#key_ty, &mut #value_ty) -> bool,
+                {
+                    #place.retain(f);
+                    self
+                }
+            });
+        }
+    }
+
+    let clear_name = method_name("clear");
+
+    setters.push(parse_quote_spanned! { ctx.field.span() =>
+        #( #attrs )*
+        #[inline(always)]
+        #vis fn #clear_name(&mut self) -> &mut Self {
+            #place.clear();
+            self
+        }
+    });
+
+    setters
+}
+
+impl Context<'_> {
+    pub fn is_collection(&self) -> bool {
+        args::merge_bool(&self.field.args.collection, &self.struct_args.collection)
+            .unwrap_or_default()
+            && (self.field.args.collection_ty().is_some() || recognize(&self.field.ty).is_some())
+    }
+
+    pub fn collection(&self) -> Collection {
+        if let Some(ty) = self.field.args.collection_ty() {
+            return Collection {
+                name: None,
+                ty: ty.clone(),
+                hasher: None,
+                is_std: false,
+            };
+        }
+
+        match recognize(&self.field.ty) {
+            Some(collection) => collection,
+            None => {
+                abort!(
+                    self.field.ty.span(),
+                    "field should be a well-known collection type"
+                );
+            }
+        }
+    }
+}
+
+/// Recognize the well-known collection types, and infer the item types like `#[set(extend)]`.
+fn recognize(ty: &Type) -> Option<Collection> {
+    let (name, args) = ty::collection_args(ty)?;
+    let collection_ty = match args.as_slice() {
+        [key_ty, value_ty, ..] if ty::is_map(name) => {
+            CollectionType::Map(Box::new((*key_ty).clone()), Box::new((*value_ty).clone()))
+        }
+        [item_ty, ..] if ty::is_set(name) => CollectionType::Set((*item_ty).clone()),
+        [item_ty, ..] => CollectionType::Seq((*item_ty).clone()),
+        _ => return None,
+    };
+
+    let hasher = match (name, args.as_slice()) {
+        ("HashMap", [_, _, hasher, ..]) | ("HashSet", [_, hasher, ..]) => Some((*hasher).clone()),
+        _ => None,
+    };
+
+    Some(Collection {
+        name: Some(name),
+        ty: collection_ty,
+        hasher,
+        is_std: ty::is_std_collection(ty),
+    })
+}
@@ -0,0 +1,220 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::emit_error;
+use quote::{format_ident, ToTokens};
+use syn::{
+    parse_quote, parse_quote_spanned, spanned::Spanned, Expr, Ident, ItemFn, Type, Visibility,
+};
+
+use crate::{
+    args::{self, AsBool, Mode},
+    field::Field as BaseField,
+};
+
+use super::{Field, FieldArgs, StructArgs};
+
+#[derive(Clone, Debug)]
+pub struct Context<'a> {
+    pub struct_args: &'a StructArgs,
+    pub struct_args_span: Option<Span>,
+    pub struct_vis: Visibility,
+    pub field: Field,
+    pub default_prefix: &'static str,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(
+        struct_args: &'a StructArgs,
+        struct_args_span: Option<Span>,
+        struct_vis: &Visibility,
+        field: BaseField,
+    ) -> Self {
+        Self::with_default_prefix(struct_args, struct_args_span, struct_vis, field, "set_")
+    }
+
+    pub fn with_default_prefix(
+        struct_args: &'a StructArgs,
+        struct_args_span: Option<Span>,
+        struct_vis: &Visibility,
+        field: BaseField,
+        default_prefix: &'static str,
+    ) -> Self {
+        let (field_args, field_args_span, mut field_attrs) =
+            args::extract::<FieldArgs, _>(field.attrs.clone(), "set", struct_args.allowed_attrs());
+
+        if let Some(meta) = field_args.attr.as_ref() {
+            field_attrs.extend(meta.args.iter().map(|meta| {
+                parse_quote_spanned! { meta.span() =>
+                    #[ #meta ]
+                }
+            }));
+        }
+
+        Self {
+            struct_args,
+            struct_args_span,
+            struct_vis: struct_vis.clone(),
+            field: Field::new(field, field_args, field_args_span, field_attrs),
+            default_prefix,
+        }
+    }
+
+    pub fn attr_span(&self) -> Span {
+        self.field
+            .args_span
+            .or(self.struct_args_span)
+            .unwrap_or(self.field.span())
+    }
+
+    pub fn vis(&self) -> Visibility {
+        args::vis(&self.field.args.vis, &self.struct_args.vis, &self.field.vis)
+    }
+
+    pub fn method_name(&self) -> Ident {
+        format_ident!(
+            "{}{}{}",
+            self.prefix(),
+            self.field.basename(),
+            self.suffix()
+        )
+    }
+
+    pub fn prefix(&self) -> String {
+        self.prefix_arg()
+            .unwrap_or_else(|| self.default_prefix.to_string())
+    }
+
+    pub fn with_prefix(&self, prefix: &str) -> String {
+        self.prefix_arg().map_or_else(
+            || format!("{}_", prefix),
+            |arg| format!("{}{}_", arg, prefix),
+        )
+    }
+
+    pub fn prefix_arg(&self) -> Option<String> {
+        args::prefix(&self.field.args.prefix, &self.struct_args.prefix)
+    }
+
+    pub fn suffix(&self) -> String {
+        args::suffix(&self.field.args.suffix, &self.struct_args.suffix).unwrap_or_default()
+    }
+
+    fn check_args(&self) {
+        let (args, struct_args) = (&self.field.args, self.struct_args);
+
+        if let Some(span) = args::misapplied_mode(&args.opt, self.is_option()) {
+            emit_error!(span, "#[set(opt)] should be applied to an `Option<T>` type");
+        }
+
+        if let Some(span) = args::misapplied_mode(&args.collection, self.is_collection()) {
+            emit_error!(
+                span,
+                "#[set(collection)] supports only some of the well-known collection types";
+                help = "#[set(collection(seq(T)))], #[set(collection(set(T)))] or #[set(collection(map(K, V)))] should be used for a custom collection"
+            );
+        }
+
+        args::check_exclusive_modes(
+            "set",
+            &[
+                Mode::new("into", self.is_into(), &args.into, &struct_args.into),
+                Mode::new(
+                    "try_into",
+                    self.is_try_into(),
+                    &args.try_into,
+                    &struct_args.try_into,
+                ),
+            ],
+            self.attr_span(),
+        );
+    }
+
+    /// Whether a conversion mode like `into` is applied to the field.
+    pub fn conversion_mode<F: AsBool, S: AsBool>(&self, field_mode: &F, struct_mode: &S) -> bool {
+        let has_field_mode = self.field.args.into.bool() || self.field.args.try_into.bool();
+
+        args::exclusive_mode(field_mode, struct_mode, has_field_mode)
+    }
+
+    /// The type of the value which is set, the inner type of `Option<T>` for `#[set(opt)]`.
+    pub fn value_ty(&self) -> &Type {
+        if self.is_option() {
+            self.option_inner_ty()
+        } else {
+            &self.field.ty
+        }
+    }
+
+    /// Assign the value to the field, which is wrapped with `Some` for `#[set(opt)]`.
+    pub fn assign_value(&self, value: Expr) -> Expr {
+        if self.is_option() {
+            self.field
+                .assign(parse_quote! { ::core::option::Option::Some( #value ) })
+        } else {
+            self.field.assign(value)
+        }
+    }
+
+    pub fn setters(&self) -> Vec<ItemFn> {
+        if self.field.args.skip {
+            return vec![];
+        }
+
+        self.check_args();
+
+        let mut setters = if self.flatten().is_some() {
+            super::flatten::setters(self)
+        } else {
+            let mut setters = vec![];
+
+            if !self.field.args.is_extend_only() {
+                setters.push(if self.is_into() {
+                    super::into::setter(self)
+                } else if self.is_try_into() {
+                    super::try_into::setter(self)
+                } else if self.is_option() {
+                    super::option::setter(self)
+                } else {
+                    super::gen::setter(self)
+                });
+            }
+
+            if self.is_extend() {
+                setters.extend(super::extend::setters(self));
+            }
+
+            setters
+        };
+
+        if self.is_option() {
+            setters.extend(super::option::utilities(self));
+        }
+
+        if self.is_collection() {
+            setters.extend(super::collection::setters(self));
+        }
+
+        if self.is_owned() {
+            let owned = setters
+                .iter()
+                .filter(|setter| super::owned::is_chained(setter))
+                .map(|setter| {
+                    let mut owned = super::owned::setter(setter.clone());
+                    owned.sig.ident = self.owned_name(&setter.sig.ident);
+                    owned
+                })
+                .collect::<Vec<_>>();
+
+            setters.into_iter().chain(owned).collect()
+        } else {
+            setters
+        }
+    }
+}
+
+impl<'a> ToTokens for Context<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for setter in self.setters() {
+            setter.to_tokens(tokens)
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro_error::{abort, emit_error};
+use quote::quote;
+use syn::{Data, DataStruct, DeriveInput, Fields, FieldsNamed, FieldsUnnamed};
+
+use crate::{
+    args,
+    field::Field,
+    methods::{self, MethodNames, Target, TraitDecl},
+    Options,
+};
+
+use super::{Context, StructArgs};
+
+pub fn expand(input: DeriveInput, options: &Options, target: &Target) -> TokenStream2 {
+    let DeriveInput {
+        attrs, vis, data, ..
+    } = input.clone();
+
+    let (struct_args, struct_args_span) =
+        args::extract_struct::<StructArgs, _>(attrs, "set", options);
+
+    if let (Some(span), None) = (struct_args.dyn_trait.span, &struct_args.trait_name) {
+        emit_error!(
+            span, "#[set(dyn)] should be applied with `trait = ...`";
+            help = "use `#[set(trait = Name, dyn)]` to return the trait object from the setters"
+        );
+    }
+
+    if let Some(ref arg) = struct_args.error {
+        if arg.value != "wrap" {
+            emit_error!(
+                arg.value, "unknown error mode `{}`", arg.value;
+                help = "use `#[set(error = wrap)]` to wrap the errors of the `try_into` setters in `SetError`"
+            );
+        }
+    }
+
+    if let Data::Struct(DataStruct { fields, .. }) = data {
+        let fields = match fields {
+            Fields::Named(FieldsNamed { named, .. }) => named,
+            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => unnamed,
+            Fields::Unit => {
+                return quote!();
+            }
+        };
+
+        let mut names = MethodNames::new("Setter");
+        let mut setters = fields
+            .into_iter()
+            .enumerate()
+            .flat_map(|(field_idx, field)| {
+                let ctx = Context::new(
+                    &struct_args,
+                    struct_args_span,
+                    &vis,
+                    Field::new(field, field_idx),
+                );
+
+                names.insert(ctx.field.name_span(), ctx.setters())
+            })
+            .collect::<Vec<_>>();
+
+        for setter in super::path::setters(&struct_args) {
+            setters.extend(names.insert(setter.sig.ident.span(), vec![setter]));
+        }
+
+        methods::expand(
+            &input,
+            target,
+            struct_args.trait_name().map(|name| TraitDecl {
+                name,
+                vis: args::vis(&None, &struct_args.vis, &vis),
+                doc: "The setters of",
+                dyn_trait: struct_args.dyn_trait.value(),
+            }),
+            args::alloc_crate(struct_args.no_std.value()),
+            methods::inline(setters, &args::inline(&struct_args.inline, "set")),
+        )
+    } else {
+        abort!(input, "#[derive(Setter)] can only be applied to structure")
+    }
+}
@@ -0,0 +1,147 @@
+use proc_macro_error::emit_error;
+use quote::format_ident;
+use syn::{
+    parse_quote, parse_quote_spanned, spanned::Spanned, GenericParam, Generics, ItemFn, Type,
+    TypeParam,
+};
+
+use crate::{
+    args::AsSpan,
+    ty::{self, TypeExt},
+};
+
+use super::Context;
+
+pub fn setters(ctx: &Context) -> Vec<ItemFn> {
+    let attrs = &ctx.field.attrs;
+    let vis = ctx.vis();
+    let basename = ctx.field.basename();
+    let extend_setter = format_ident!("{}{}{}", ctx.with_prefix("extend"), &basename, ctx.suffix());
+    let append_setter = format_ident!("{}{}{}", ctx.with_prefix("append"), &basename, ctx.suffix());
+
+    let (item_ty, extend_generic_param, append_generic): (
+        _,
+        Option<GenericParam>,
+        Option<Generics>,
+    ) = if let Some(extend) = ctx.field.args.extend() {
+        use super::args::Extend::*;
+
+        match extend {
+            Type(ty) => (ty.clone(), None, None),
+            Bound(param @ TypeParam { ident, .. }) => (
+                parse_quote_spanned! { param.span() =>
+                    #ident
+                },
+                Some(GenericParam::Type(param.clone())),
+                Some(parse_quote_spanned! { param.span() =>
+                    < #param >
+                }),
+            ),
+        }
+    } else {
+        match ctx.extend_item_ty() {
+            Some(ty) => (ty, None, None),
+            None => return vec![],
+        }
+    };
+
+    let field_place = ctx.field.place_mut();
+    let arg_name = ctx.field.basename();
+
+    if ctx.is_into() {
+        // the items are converted with the `Into` trait
+        let params = extend_generic_param.into_iter().collect::<Vec<_>>();
+
+        return vec![
+            parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #extend_setter < ITER, ARG, #( #params ),* > (&mut self, #arg_name: ITER) -> &mut Self
+                where
+                    ITER: ::core::iter::IntoIterator<Item = ARG>,
+                    ARG: ::core::convert::Into<#item_ty>,
+                {
+                    #field_place.extend( ::core::iter::IntoIterator::into_iter(#arg_name).map(::core::convert::Into::into) );
+                    self
+                }
+            },
+            parse_quote_spanned! { ctx.field.span() =>
+                #( #attrs )*
+                #[inline(always)]
+                #vis fn #append_setter < ARG, #( #params ),* > (&mut self, #arg_name: ARG) -> &mut Self
+                where
+                    ARG: ::core::convert::Into<#item_ty>,
+                {
+                    #field_place.extend([ ::core::convert::Into::<#item_ty>::into(#arg_name) ]);
+                    self
+                }
+            },
+        ];
+    }
+
+    let extend_generic: Generics = {
+        let params = Some(parse_quote_spanned! { ctx.field.ty.span() =>
+            ITER: ::core::iter::IntoIterator<Item = #item_ty>
+        })
+        .into_iter()
+        .chain(extend_generic_param);
+
+        parse_quote_spanned! { ctx.field.ty.span() =>
+            < #( #params ),* >
+        }
+    };
+
+    vec![
+        parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #extend_setter #extend_generic (&mut self, #arg_name: ITER) -> &mut Self {
+                #field_place.extend( #arg_name );
+                self
+            }
+        },
+        parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #append_setter #append_generic (&mut self, #arg_name: #item_ty) -> &mut Self {
+                #field_place.extend([ #arg_name ]);
+                self
+            }
+        },
+    ]
+}
+
+impl Context<'_> {
+    pub fn is_extend(&self) -> bool {
+        self.field.args.extend.is_some()
+    }
+
+    pub fn extend_item_ty(&self) -> Option<Type> {
+        let ty = &self.field.ty;
+
+        if ty.is_string() || self.field.ty.is_ref_string() {
+            return Some(parse_quote! { char });
+        } else if let Some((name, args)) = ty::collection_args(ty) {
+            // the arity of the generic arguments is checked by `collection_args`
+            return Some(if ty::is_map(name) {
+                let (key_ty, value_ty) = (args[0], args[1]);
+
+                Type::Tuple(parse_quote! { (#key_ty, #value_ty) })
+            } else {
+                args[0].clone()
+            });
+        }
+
+        emit_error!(
+            self.field
+                .args
+                .extend
+                .as_span()
+                .unwrap_or_else(|| self.field.ty.span()),
+            "#[set(extend)] supports only some of the well-known types,
+#[set(extend(Item))] should be used for a type which implements the `Extend<Item>` trait"
+        );
+
+        None
+    }
+}
@@ -0,0 +1,67 @@
+use quote::format_ident;
+use syn::{parse_quote_spanned, Attribute, ItemFn, Visibility};
+
+use crate::args::{self, Flatten};
+
+use super::Context;
+
+/// Forward the declared setters of a nested field, which are named like `set_x` in the nested type by default.
+pub fn setters(ctx: &Context) -> Vec<ItemFn> {
+    let flatten = ctx.flatten().unwrap();
+    // the doc comments describe the nested field instead of the forwarded setters
+    let attrs = ctx
+        .field
+        .attrs
+        .iter()
+        .filter(|attr| !attr.path().is_ident("doc"))
+        .collect::<Vec<&Attribute>>();
+    let vis = ctx.flatten_vis();
+    let place = ctx.field.place_mut();
+
+    flatten
+        .methods
+        .iter()
+        .filter(|method| method.modes.check("set", "flatten", &["into"]))
+        .map(|method| {
+            let arg_name = &method.name;
+            let inner_name = flatten.inner_name("set_", arg_name);
+            let method_name = format_ident!("{}{}{}", ctx.prefix(), arg_name, ctx.suffix());
+            let ty = &method.ty;
+
+            if method.modes.has("into") {
+                parse_quote_spanned! { method.name.span() =>
+                    #( #attrs )*
+                    #[inline(always)]
+                    #vis fn #method_name<ARG: ::core::convert::Into<#ty>>(&mut self, #arg_name: ARG) -> &mut Self {
+                        #place.#inner_name(#arg_name.into());
+                        self
+                    }
+                }
+            } else {
+                parse_quote_spanned! { method.name.span() =>
+                    #( #attrs )*
+                    #[inline(always)]
+                    #vis fn #method_name(&mut self, #arg_name: #ty) -> &mut Self {
+                        #place.#inner_name(#arg_name);
+                        self
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+impl Context<'_> {
+    /// The forwarded setters default to the visibility of the structure instead of the nested field.
+    pub fn flatten_vis(&self) -> Visibility {
+        args::vis(
+            &self.field.args.vis,
+            &self.struct_args.vis,
+            &self.struct_vis,
+        )
+    }
+
+    pub fn flatten(&self) -> Option<&Flatten> {
+        self.field.args.flatten.as_ref().map(|arg| &arg.args)
+    }
+}
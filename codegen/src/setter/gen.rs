@@ -1,4 +1,4 @@
-use syn::{parse_quote_spanned, spanned::Spanned, ItemFn};
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, ItemFn};
 
 use super::Context;
 
@@ -7,14 +7,14 @@ pub fn setter(ctx: &Context) -> ItemFn {
     let vis = ctx.vis();
     let method_name = ctx.method_name();
     let ty = ctx.field.ty.clone();
-    let field_name = ctx.field.name();
     let arg_name = ctx.field.basename();
+    let assign = ctx.field.assign(parse_quote! { #arg_name });
 
     parse_quote_spanned! { ctx.field.span() =>
         #( #attrs )*
         #[inline(always)]
         #vis fn #method_name(&mut self, #arg_name: #ty) -> &mut Self {
-            #field_name = #arg_name;
+            #assign;
             self
         }
     }
@@ -1,6 +1,4 @@
-use syn::{parse_quote_spanned, ItemFn};
-
-use crate::args;
+use syn::{parse_quote, parse_quote_spanned, ItemFn};
 
 use super::Context;
 
@@ -8,18 +6,18 @@ pub fn setter(ctx: &Context) -> ItemFn {
     let attrs = &ctx.field.attrs;
     let vis = ctx.vis();
     let method_name = ctx.method_name();
-    let ty = ctx.field.ty.clone();
-    let field_name = ctx.field.name();
+    let ty = ctx.value_ty();
     let arg_name = ctx.field.basename();
+    let assign = ctx.assign_value(parse_quote! { ::core::convert::Into::into( #arg_name ) });
 
     parse_quote_spanned! { ctx.attr_span() =>
         #( #attrs )*
         #[inline(always)]
         #vis fn #method_name<ARG>(&mut self, #arg_name: ARG) -> &mut Self
         where
-            ARG : ::std::convert::Into<#ty>
+            ARG : ::core::convert::Into<#ty>
         {
-            #field_name = ::std::convert::Into::into( #arg_name );
+            #assign;
             self
         }
     }
@@ -27,6 +25,6 @@ pub fn setter(ctx: &Context) -> ItemFn {
 
 impl Context<'_> {
     pub fn is_into(&self) -> bool {
-        args::merge_bool(&self.field.args.into, &self.struct_args.into).unwrap_or_default()
+        self.conversion_mode(&self.field.args.into, &self.struct_args.into)
     }
 }
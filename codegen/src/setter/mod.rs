@@ -1,17 +1,16 @@
-#![allow(clippy::module_inception)]
-
 mod args;
-mod borrow;
-mod bytes;
-mod clone;
+mod collection;
 mod context;
-mod copy;
 mod expand;
+mod extend;
 mod field;
+mod flatten;
 mod gen;
+mod into;
 mod option;
-mod slice;
-mod str;
+pub mod owned;
+mod path;
+mod try_into;
 
 pub use self::args::{FieldArgs, StructArgs};
 pub use self::context::Context;
@@ -0,0 +1,103 @@
+use proc_macro_error::abort;
+use quote::format_ident;
+use structmeta::Flag;
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, ItemFn, Type};
+
+use crate::{args, ty::TypeExt};
+
+use super::{args::OptArgs, Context};
+
+pub fn setter(ctx: &Context) -> ItemFn {
+    let attrs = &ctx.field.attrs;
+    let vis = ctx.vis();
+    let method_name = ctx.method_name();
+    let inner_ty = ctx.option_inner_ty();
+    let arg_name = ctx.field.basename();
+    let assign = ctx
+        .field
+        .assign(parse_quote! { ::core::option::Option::Some( #arg_name ) });
+
+    parse_quote_spanned! { ctx.field.span() =>
+        #( #attrs )*
+        #[inline(always)]
+        #vis fn #method_name(&mut self, #arg_name: #inner_ty) -> &mut Self {
+            #assign;
+            self
+        }
+    }
+}
+
+/// The `take_x`, `replace_x` and `clear_x` setters enabled by `#[set(opt(...))]`.
+pub fn utilities(ctx: &Context) -> Vec<ItemFn> {
+    let attrs = &ctx.field.attrs;
+    let vis = ctx.vis();
+    let basename = ctx.field.basename();
+    let suffix = ctx.suffix();
+    let inner_ty = ctx.option_inner_ty();
+    let mut setters = vec![];
+
+    let field_ref = ctx.field.mut_ref();
+
+    if ctx.has_opt_arg(|arg| &arg.take) {
+        let take_name = format_ident!("{}{}{}", ctx.with_prefix("take"), basename, suffix);
+
+        setters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #take_name(&mut self) -> ::core::option::Option<#inner_ty> {
+                ::core::option::Option::take( #field_ref )
+            }
+        });
+    }
+
+    if ctx.has_opt_arg(|arg| &arg.replace) {
+        let replace_name = format_ident!("{}{}{}", ctx.with_prefix("replace"), basename, suffix);
+
+        setters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #replace_name(&mut self, #basename: #inner_ty) -> ::core::option::Option<#inner_ty> {
+                ::core::option::Option::replace( #field_ref, #basename )
+            }
+        });
+    }
+
+    if ctx.has_opt_arg(|arg| &arg.clear) {
+        let clear_name = format_ident!("{}{}{}", ctx.with_prefix("clear"), basename, suffix);
+        let assign = ctx
+            .field
+            .assign(parse_quote! { ::core::option::Option::None });
+
+        setters.push(parse_quote_spanned! { ctx.field.span() =>
+            #( #attrs )*
+            #[inline(always)]
+            #vis fn #clear_name(&mut self) -> &mut Self {
+                #assign;
+                self
+            }
+        });
+    }
+
+    setters
+}
+
+impl Context<'_> {
+    pub fn is_option(&self) -> bool {
+        args::merge_bool(&self.field.args.opt, &self.struct_args.opt).unwrap_or_default()
+            && self.field.ty.option_inner_ty().is_some()
+    }
+
+    pub fn has_opt_arg(&self, flag: fn(&OptArgs) -> &Flag) -> bool {
+        args::toggle_args(&self.field.args.opt, &self.struct_args.opt)
+            .any(|arg| flag(arg).span.is_some())
+    }
+
+    pub fn option_inner_ty(&self) -> &Type {
+        match self.field.ty.option_inner_ty() {
+            Some(ty) => ty,
+            None => {
+                abort!(self.field.ty.span(), "field should be an `Option<T>` type");
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use quote::format_ident;
+use syn::{
+    parse_quote, FnArg, GenericArgument, Ident, ItemFn, PathArguments, ReturnType, Type, TypePath,
+    TypeReference,
+};
+
+use crate::{args, ty::TypeExt};
+
+use super::Context;
+
+/// Convert a `fn(&mut self, ..) -> &mut Self` setter to a `fn(mut self, ..) -> Self` one.
+pub fn setter(mut setter: ItemFn) -> ItemFn {
+    if let Some(receiver @ FnArg::Receiver(_)) = setter.sig.inputs.first_mut() {
+        *receiver = parse_quote! { mut self };
+    }
+
+    if let ReturnType::Type(_, ref mut ty) = setter.sig.output {
+        owned_ty(ty);
+    }
+
+    setter
+}
+
+/// The setter returns `&mut Self` for chaining, which may be wrapped like `Result<&mut Self, E>`.
+pub fn is_chained(setter: &ItemFn) -> bool {
+    match setter.sig.output {
+        ReturnType::Type(_, ref ty) => owned_ty(&mut ty.as_ref().clone()),
+        ReturnType::Default => false,
+    }
+}
+
+/// Replace the `&mut Self` in the type with `Self`, returns whether the type has been changed.
+fn owned_ty(ty: &mut Type) -> bool {
+    match ty {
+        Type::Reference(TypeReference {
+            mutability: Some(_),
+            ref elem,
+            ..
+        }) if elem.is_ty("Self") => {
+            *ty = parse_quote! { Self };
+
+            true
+        }
+        Type::Path(TypePath { ref mut path, .. }) => {
+            let mut changed = false;
+
+            for segment in path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(ref mut args) = segment.arguments {
+                    for arg in args.args.iter_mut() {
+                        if let GenericArgument::Type(ref mut ty) = arg {
+                            changed |= owned_ty(ty);
+                        }
+                    }
+                }
+            }
+
+            changed
+        }
+        _ => false,
+    }
+}
+
+impl Context<'_> {
+    pub fn is_owned(&self) -> bool {
+        args::merge_bool(&self.field.args.owned, &self.struct_args.owned).unwrap_or_default()
+    }
+
+    /// The name of the owned setter, like `with_x` for `set_x` or `with_extend_x` for `extend_x`.
+    pub fn owned_name(&self, setter_name: &Ident) -> Ident {
+        let name = setter_name.to_string();
+        let prefix = self.prefix();
+
+        format_ident!(
+            "{}{}",
+            self.with_prefix("with"),
+            name.strip_prefix(prefix.as_str()).unwrap_or(&name)
+        )
+    }
+}
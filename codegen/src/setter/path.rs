@@ -0,0 +1,71 @@
+use quote::format_ident;
+use syn::{parse_quote_spanned, ItemFn, Visibility};
+
+use crate::args::{self, AsBool};
+
+use super::StructArgs;
+
+/// Generate the setters writing through the field paths of `#[set(path(...))]`.
+pub fn setters(struct_args: &StructArgs) -> Vec<ItemFn> {
+    let vis = args::vis(&None, &struct_args.vis, &Visibility::Inherited);
+    let prefix_arg = args::prefix(&None, &struct_args.prefix);
+    let suffix = args::suffix(&None, &struct_args.suffix).unwrap_or_default();
+    let is_owned = struct_args.owned.bool();
+
+    struct_args
+        .paths()
+        .flat_map(|method| {
+            if !method.modes.check("set", "path", &["into"]) {
+                return vec![];
+            }
+
+            let arg_name = &method.name;
+            let method_name = format_ident!(
+                "{}{}{}",
+                prefix_arg.as_deref().unwrap_or("set_"),
+                arg_name,
+                suffix
+            );
+            let ty = match method.ty("set") {
+                Some(ty) => ty,
+                None => return vec![],
+            };
+            let place = method.path.place_mut();
+
+            let setter: ItemFn = if method.modes.has("into") {
+                parse_quote_spanned! { method.name.span() =>
+                    #[inline(always)]
+                    #vis fn #method_name<ARG>(&mut self, #arg_name: ARG) -> &mut Self
+                    where
+                        ARG : ::core::convert::Into<#ty>
+                    {
+                        #place = ::core::convert::Into::into( #arg_name );
+                        self
+                    }
+                }
+            } else {
+                parse_quote_spanned! { method.name.span() =>
+                    #[inline(always)]
+                    #vis fn #method_name(&mut self, #arg_name: #ty) -> &mut Self {
+                        #place = #arg_name;
+                        self
+                    }
+                }
+            };
+
+            if is_owned {
+                let mut owned = super::owned::setter(setter.clone());
+                owned.sig.ident = format_ident!(
+                    "{}with_{}{}",
+                    prefix_arg.as_deref().unwrap_or_default(),
+                    arg_name,
+                    suffix
+                );
+
+                vec![setter, owned]
+            } else {
+                vec![setter]
+            }
+        })
+        .collect()
+}
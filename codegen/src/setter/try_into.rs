@@ -0,0 +1,46 @@
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Expr, ItemFn, Type};
+
+use super::Context;
+
+pub fn setter(ctx: &Context) -> ItemFn {
+    let attrs = &ctx.field.attrs;
+    let vis = ctx.vis();
+    let method_name = ctx.method_name();
+    let ty = ctx.value_ty();
+    let arg_name = ctx.field.basename();
+    let try_into: Expr = parse_quote! {
+        ::core::convert::TryInto::<#ty>::try_into( #arg_name )
+    };
+    let error: Type = parse_quote! { <ARG as ::core::convert::TryInto<#ty>>::Error };
+    let (value, error): (Expr, Type) = if ctx.struct_args.wrap_error() {
+        let krate = ctx.struct_args.crate_path();
+        let field_name = ctx.field.name_str();
+
+        (
+            parse_quote! { #try_into.map_err(|err| #krate::SetError::new(#field_name, err))? },
+            parse_quote! { #krate::SetError<#error> },
+        )
+    } else {
+        (parse_quote! { #try_into? }, error)
+    };
+    let assign = ctx.assign_value(value);
+
+    parse_quote_spanned! { ctx.field.span() =>
+        #( #attrs )*
+        #[inline(always)]
+        #vis fn #method_name<ARG>(&mut self, #arg_name: ARG)
+            -> ::core::result::Result<&mut Self, #error>
+        where
+            ARG : ::core::convert::TryInto<#ty>
+        {
+            #assign;
+            Ok(self)
+        }
+    }
+}
+
+impl Context<'_> {
+    pub fn is_try_into(&self) -> bool {
+        self.conversion_mode(&self.field.args.try_into, &self.struct_args.try_into)
+    }
+}
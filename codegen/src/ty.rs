@@ -0,0 +1,209 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    AngleBracketedGenericArguments, GenericArgument, Lifetime, PathArguments, Type, TypeArray,
+    TypePath, TypeReference,
+};
+
+pub const WELL_KNOWN_SEQ: &[&str] = &[
+    "BinaryHeap",
+    "BTreeSet",
+    "HashSet",
+    "LinkedList",
+    "Vec",
+    "VecDeque",
+];
+
+pub const WELL_KNOWN_MAP: &[&str] = &["HashMap", "BTreeMap"];
+
+/// The bounds of the key to lookup a well-known set or map.
+pub fn lookup_bounds(name: &str) -> TokenStream {
+    if name.starts_with("Hash") {
+        quote! { ::core::hash::Hash + ::core::cmp::Eq }
+    } else {
+        quote! { ::core::cmp::Ord }
+    }
+}
+
+/// Inspect the types of the fields, the types are recognized by the last segment of their paths.
+pub trait TypeExt {
+    /// The type is named like `String` or `std::string::String`.
+    fn is_ty(&self, name: &str) -> bool;
+
+    /// The type is a reference of the named type, like `&String`.
+    fn is_ref_ty(&self, name: &str) -> bool;
+
+    /// The type is a `String`.
+    fn is_string(&self) -> bool;
+
+    /// The type is a `&String`.
+    fn is_ref_string(&self) -> bool;
+
+    /// The referenced type of `&T` or `&mut T`.
+    fn ref_elem_ty(&self) -> Option<&Type>;
+
+    /// The lifetime of a shared reference like `&'a T`.
+    fn ref_lifetime(&self) -> Option<&Lifetime>;
+
+    /// The element type of an array `[T; N]`.
+    fn array_elem_ty(&self) -> Option<&Type>;
+
+    /// The inner type of an `Option<T>`.
+    fn option_inner_ty(&self) -> Option<&Type>;
+
+    /// The element type of a `Vec<T>` or `[T; N]`.
+    fn slice_inner_ty(&self) -> Option<&Type>;
+
+    /// The first generic argument of the named type, like `T` of `Box<T>`.
+    fn inner_ty(&self, name: &str) -> Option<&Type>;
+}
+
+impl TypeExt for Type {
+    fn is_ty(&self, name: &str) -> bool {
+        matches!(self,
+            Type::Path(TypePath {
+                ref qself,
+                ref path,
+            }) if qself.is_none()
+                && path
+                    .segments
+                    .last()
+                    .map(|s| s.ident == name)
+                    .unwrap_or_default())
+    }
+
+    fn is_string(&self) -> bool {
+        self.is_ty("String")
+    }
+
+    fn is_ref_string(&self) -> bool {
+        self.is_ref_ty("String")
+    }
+
+    fn is_ref_ty(&self, name: &str) -> bool {
+        matches!(self,
+           Type::Reference(TypeReference { ref elem, .. }) if elem.as_ref().is_ty(name))
+    }
+
+    fn ref_elem_ty(&self) -> Option<&Type> {
+        if let Type::Reference(TypeReference { ref elem, .. }) = self {
+            Some(elem.as_ref())
+        } else {
+            None
+        }
+    }
+
+    fn ref_lifetime(&self) -> Option<&Lifetime> {
+        if let Type::Reference(TypeReference {
+            ref lifetime,
+            mutability: None,
+            ..
+        }) = self
+        {
+            lifetime.as_ref()
+        } else {
+            None
+        }
+    }
+
+    fn array_elem_ty(&self) -> Option<&Type> {
+        if let Type::Array(TypeArray { ref elem, .. }) = self {
+            Some(elem.as_ref())
+        } else {
+            None
+        }
+    }
+
+    fn option_inner_ty(&self) -> Option<&Type> {
+        self.inner_ty("Option")
+    }
+
+    fn slice_inner_ty(&self) -> Option<&Type> {
+        self.inner_ty("Vec").or(self.array_elem_ty())
+    }
+
+    fn inner_ty(&self, name: &str) -> Option<&Type> {
+        generic_args_ty(self, [name]).and_then(|args| args.into_iter().next())
+    }
+}
+
+/// Recognize a well-known collection type, and its generic arguments like `[T]`, or `[K, V]` of a map.
+pub fn collection_args(ty: &Type) -> Option<(&'static str, Vec<&Type>)> {
+    WELL_KNOWN_SEQ
+        .iter()
+        .chain(WELL_KNOWN_MAP)
+        .find_map(|&name| {
+            let expected = if is_map(name) { 2 } else { 1 };
+
+            generic_args_ty(ty, [name])
+                .filter(|args| args.len() >= expected)
+                .map(|args| (name, args))
+        })
+}
+
+/// Whether the collection is named without a path like `HashMap`, or with the path of `std` or `alloc`,
+/// like `std::collections::HashMap` or `alloc::collections::btree_map::BTreeMap`.
+pub fn is_std_collection(ty: &Type) -> bool {
+    let path = match ty {
+        Type::Path(TypePath { qself: None, path }) => path,
+        _ => return false,
+    };
+    let modules = path
+        .segments
+        .iter()
+        .take(path.segments.len() - 1)
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+
+    match modules.as_slice() {
+        [] => path.leading_colon.is_none(),
+        [krate, collections] | [krate, collections, _] => {
+            (krate == "std" || krate == "alloc") && collections == "collections"
+        }
+        _ => false,
+    }
+}
+
+pub fn is_map(name: &str) -> bool {
+    WELL_KNOWN_MAP.contains(&name)
+}
+
+pub fn is_set(name: &str) -> bool {
+    matches!(name, "HashSet" | "BTreeSet")
+}
+
+pub fn generic_args_ty<I: IntoIterator<Item = S>, S: AsRef<str>>(
+    ty: &Type,
+    names: I,
+) -> Option<Vec<&Type>> {
+    match ty {
+        Type::Path(TypePath {
+            ref qself,
+            ref path,
+        }) if qself.is_none()
+            && path
+                .segments
+                .last()
+                .map(|s| names.into_iter().any(|name| s.ident == name))
+                .unwrap_or_default() =>
+        {
+            match path.segments.last().as_ref().unwrap().arguments {
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    ref args, ..
+                }) => Some(
+                    args.into_iter()
+                        .flat_map(|arg| {
+                            if let GenericArgument::Type(ty) = arg {
+                                Some(ty)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,53 @@
+use getset2_codegen::{
+    extract_field_args, extract_struct_args, GetterFieldArgs, GetterStructArgs, Options,
+    SetterFieldArgs, TypeExt,
+};
+use quote::quote;
+use syn::{parse_quote, Field, ItemStruct, Type};
+
+#[test]
+fn extract_args() {
+    let item: ItemStruct = parse_quote! {
+        #[get(prefix = "get")]
+        struct Foo {
+            #[get(skip)]
+            #[set(into)]
+            name: String,
+        }
+    };
+    let field: &Field = item.fields.iter().next().unwrap();
+
+    let struct_args: GetterStructArgs = extract_struct_args(&item.attrs, &Options::default());
+    let field_args: GetterFieldArgs = extract_field_args(&field.attrs);
+    let set_args: SetterFieldArgs = extract_field_args(&field.attrs);
+
+    assert_eq!(struct_args.prefix.unwrap().value.value(), "get");
+    assert!(field_args.skip);
+    assert!(set_args.into.is_some());
+    assert!(field.ty.is_string());
+}
+
+#[test]
+fn extract_struct_args_with_defaults() {
+    let item: ItemStruct = parse_quote! {
+        #[get(copy)]
+        struct Foo(u32);
+    };
+    let mut options = Options::default();
+
+    options.get_defaults = Some(quote! { prefix = "get" });
+
+    let args: GetterStructArgs = extract_struct_args(&item.attrs, &options);
+
+    assert!(args.copy.value());
+    assert_eq!(args.prefix.unwrap().value.value(), "get");
+}
+
+#[test]
+fn type_ext() {
+    let ty: Type = parse_quote! { Option<Vec<u8>> };
+
+    assert!(ty.is_ty("Option"));
+    assert!(ty.option_inner_ty().unwrap().is_ty("Vec"));
+    assert!(ty.option_inner_ty().unwrap().slice_inner_ty().is_some());
+}
@@ -0,0 +1,45 @@
+use std::{env, path::PathBuf};
+
+use getset2_codegen::Options;
+use syn::parse_quote;
+
+// the manifest directory is shared by the process, so the fixtures are expanded in a single test
+#[test]
+fn expand_with_config() {
+    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut options = Options::default();
+
+    options.config = true;
+
+    let expand = |fixture: &str| {
+        env::set_var("CARGO_MANIFEST_DIR", fixtures.join(fixture));
+
+        getset2_codegen::expand_getter(
+            parse_quote! {
+                struct Foo {
+                    #[get(copy)]
+                    port: u16,
+                }
+            },
+            &options,
+        )
+        .to_string()
+    };
+
+    let tokens = expand("config_file");
+
+    assert!(
+        tokens.contains("# [inline (never)] pub (crate) fn get_port"),
+        "{}",
+        tokens
+    );
+    assert!(tokens.contains("include_bytes !"), "{}", tokens);
+
+    let tokens = expand("config_metadata");
+
+    assert!(tokens.contains("pub (crate) fn get_port"), "{}", tokens);
+
+    let tokens = expand("config_precedence");
+
+    assert!(tokens.contains("fn from_file_port"), "{}", tokens);
+}
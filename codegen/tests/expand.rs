@@ -0,0 +1,31 @@
+use getset2_codegen::Options;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+fn input() -> DeriveInput {
+    parse_quote! {
+        #[get(copy)]
+        struct Foo {
+            #[set(into)]
+            port: u16,
+        }
+    }
+}
+
+#[test]
+fn expand_getter() {
+    let tokens = getset2_codegen::expand_getter(input(), &Options::default()).to_string();
+
+    assert!(tokens.contains("fn port (& self) -> u16"), "{}", tokens);
+}
+
+#[test]
+fn expand_with_defaults() {
+    let mut options = Options::default();
+
+    options.set_defaults = Some(quote! { prefix = "with" });
+
+    let tokens = getset2_codegen::expand_setter(input(), &options).to_string();
+
+    assert!(tokens.contains("fn with_port"), "{}", tokens);
+}
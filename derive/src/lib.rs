@@ -0,0 +1,113 @@
+//! The derives of [`getset2`](https://docs.rs/getset2), which are re-exported and documented by the `getset2` crate,
+//! so the generated code can refer to its runtime items like `getset2::SetError`.
+
+use proc_macro::TokenStream;
+use proc_macro_error::{proc_macro_error, set_dummy};
+use syn::{parse_macro_input, DeriveInput};
+
+use getset2_codegen::{CompatKind, Options};
+
+#[proc_macro_derive(Getter, attributes(get, get_copy, get_mut, getset))]
+#[proc_macro_error]
+pub fn getter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = getset2_codegen::expand_getter(input, &options());
+
+    // keep the valid accessors along with the emitted errors, so the users don't fail on them
+    set_dummy(expanded.clone());
+
+    expanded.into()
+}
+
+#[proc_macro_derive(Setter, attributes(set, getset))]
+#[proc_macro_error]
+pub fn setter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = getset2_codegen::expand_setter(input, &options());
+
+    set_dummy(expanded.clone());
+
+    expanded.into()
+}
+
+#[proc_macro_derive(Builder, attributes(builder, set, getset))]
+#[proc_macro_error]
+pub fn builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = getset2_codegen::expand_builder(input, &options());
+
+    set_dummy(expanded.clone());
+
+    expanded.into()
+}
+
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn accessors(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as getset2_codegen::AccessorsArgs);
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = getset2_codegen::expand_accessors(args, input, &options());
+
+    set_dummy(expanded.clone());
+
+    expanded.into()
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn impl_accessors(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as getset2_codegen::Accessors);
+
+    let expanded = getset2_codegen::expand_impl_accessors(input, &options());
+
+    set_dummy(expanded.clone());
+
+    expanded.into()
+}
+
+#[proc_macro_derive(Getters, attributes(get, getset))]
+#[proc_macro_error]
+pub fn getters(input: TokenStream) -> TokenStream {
+    compat(input, CompatKind::Get)
+}
+
+#[proc_macro_derive(MutGetters, attributes(get_mut, getset))]
+#[proc_macro_error]
+pub fn mut_getters(input: TokenStream) -> TokenStream {
+    compat(input, CompatKind::GetMut)
+}
+
+#[proc_macro_derive(CopyGetters, attributes(get_copy, getset))]
+#[proc_macro_error]
+pub fn copy_getters(input: TokenStream) -> TokenStream {
+    compat(input, CompatKind::GetCopy)
+}
+
+#[proc_macro_derive(Setters, attributes(set, getset))]
+#[proc_macro_error]
+pub fn setters(input: TokenStream) -> TokenStream {
+    compat(input, CompatKind::Set)
+}
+
+fn compat(input: TokenStream, kind: CompatKind) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = getset2_codegen::expand_compat(input, kind, &options());
+
+    set_dummy(expanded.clone());
+
+    expanded.into()
+}
+
+// the derives read the crate-wide defaults and understand the attributes of `getset`
+fn options() -> Options {
+    let mut options = Options::default();
+
+    options.config = true;
+    options.compat = true;
+    options
+}
@@ -0,0 +1,64 @@
+use core::fmt;
+
+/// An error returned by the `try_into` setters of `#[set(error = wrap)]`,
+/// when the value can't be converted to the type of the field.
+///
+/// ```
+/// use getset2::Setter;
+///
+/// #[derive(Debug, Default, Setter)]
+/// #[set(error = wrap)]
+/// pub struct Foo {
+///     #[set(try_into)]
+///     port: u16,
+/// }
+///
+/// let err = Foo::default().set_port(65536).unwrap_err();
+///
+/// assert_eq!(err.field(), "port");
+/// assert_eq!(err.to_string(), "invalid value of field `port`, out of range integral type conversion attempted");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetError<E> {
+    field: &'static str,
+    error: E,
+}
+
+impl<E> SetError<E> {
+    /// Create an error of the field with the error of the conversion.
+    #[inline(always)]
+    pub fn new(field: &'static str, error: E) -> Self {
+        SetError { field, error }
+    }
+
+    /// The name of the field, or the index of an unnamed field.
+    #[inline(always)]
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// The error of the conversion.
+    #[inline(always)]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Unwrap the error of the conversion.
+    #[inline(always)]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for SetError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value of field `{}`, {}", self.field, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for SetError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
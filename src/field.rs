@@ -1,29 +1,75 @@
-use derive_more::{Constructor, Deref};
-use syn::{parse_quote_spanned, spanned::Spanned, ExprField, Index, Member};
+/// A field of the structure marked with the type `N`, which is implemented by `#[get(field = N)]`.
+///
+/// The marker type is shared by the structures, so the generic code works with the fields of different structures.
+///
+/// ```
+/// use getset2::{Field, FieldMut, Getter};
+///
+/// /// The marker of the `name` fields.
+/// pub struct Name;
+///
+/// #[derive(Getter)]
+/// pub struct Foo {
+///     #[get(field = Name, mut)]
+///     name: String,
+/// }
+///
+/// #[derive(Getter)]
+/// pub struct Bar {
+///     #[get(field = Name, mut)]
+///     title: String,
+/// }
+///
+/// fn shout<T: FieldMut<Name, Type = String>>(value: &mut T) -> &str {
+///     value.field_mut().make_ascii_uppercase();
+///     value.field()
+/// }
+///
+/// let mut foo = Foo { name: "foo".to_string() };
+/// let mut bar = Bar { title: "bar".to_string() };
+///
+/// assert_eq!(shout(&mut foo), "FOO");
+/// assert_eq!(shout(&mut bar), "BAR");
+/// ```
+pub trait Field<N> {
+    /// The type of the field.
+    type Type: ?Sized;
 
-#[derive(Clone, Debug, Constructor, Deref)]
-pub struct Field {
-    #[deref]
-    pub field: syn::Field,
-    pub idx: usize,
+    /// Borrow the field.
+    fn field(&self) -> &Self::Type;
 }
 
-impl Field {
-    pub fn name(&self) -> ExprField {
-        match self.field.ident {
-            Some(ref name) => parse_quote_spanned! { self.field.span() =>
-                self.#name
-            },
-            None => {
-                let idx = Member::Unnamed(Index {
-                    index: self.idx as u32,
-                    span: self.field.span(),
-                });
+/// A field which can be mutably borrowed, which is implemented by `#[get(field = N, mut)]`.
+pub trait FieldMut<N>: Field<N> {
+    /// Mutably borrow the field.
+    fn field_mut(&mut self) -> &mut Self::Type;
+}
+
+/// The names of the fields of a structure, which is implemented by the enum of `#[get(field_names)]`.
+///
+/// ```
+/// use getset2::{FieldName, Getter};
+///
+/// #[derive(Getter)]
+/// #[get(field_names)]
+/// pub struct Foo {
+///     user_id: u64,
+///     name: String,
+/// }
+///
+/// assert_eq!(FooField::ALL, &[FooField::UserId, FooField::Name]);
+/// assert_eq!(FooField::UserId.name(), "user_id");
+/// assert_eq!(FooField::from_name("name"), Some(FooField::Name));
+/// ```
+pub trait FieldName: Copy + Eq + 'static {
+    /// The fields in the declared order.
+    const ALL: &'static [Self];
+
+    /// The name of the field.
+    fn name(self) -> &'static str;
 
-                parse_quote_spanned! { self.field.span() =>
-                    self.#idx
-                }
-            }
-        }
+    /// Find the field of the name.
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|field| field.name() == name)
     }
 }
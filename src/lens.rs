@@ -0,0 +1,144 @@
+use core::{fmt, marker::PhantomData};
+
+use crate::FieldMut;
+
+/// A field of the source `S`, which can be borrowed and mutably borrowed by the generic code.
+///
+/// The getters and mutable getters generated by [`Getter`](derive.Getter.html) can be paired with [`lens`],
+/// or the fields of `#[get(field = N, mut)]` can be selected with [`field_lens`],
+/// so the generic code works with the fields of different structures.
+///
+/// ```
+/// use getset2::{lens, Getter, Lens};
+///
+/// #[derive(Default, Getter)]
+/// #[get(mut)]
+/// pub struct Foo {
+///     name: String,
+/// }
+///
+/// #[derive(Default, Getter)]
+/// #[get(mut)]
+/// pub struct Bar {
+///     title: String,
+/// }
+///
+/// fn shout<S, L: Lens<S, Target = String>>(source: &mut S, lens: L) -> &str {
+///     lens.get_mut(source).make_ascii_uppercase();
+///     lens.get(source)
+/// }
+///
+/// let mut foo = Foo { name: "foo".to_string() };
+/// let mut bar = Bar { title: "bar".to_string() };
+///
+/// assert_eq!(shout(&mut foo, lens(Foo::name, Foo::name_mut)), "FOO");
+/// assert_eq!(shout(&mut bar, lens(Bar::title, Bar::title_mut)), "BAR");
+/// ```
+///
+/// ```
+/// use getset2::{field_lens, Getter, Lens};
+///
+/// pub struct Name;
+///
+/// #[derive(Getter)]
+/// pub struct Foo {
+///     #[get(field = Name, mut)]
+///     name: String,
+/// }
+///
+/// let lens = field_lens::<Name>();
+/// let mut foo = Foo { name: "foo".to_string() };
+///
+/// lens.get_mut(&mut foo).push('!');
+///
+/// assert_eq!(lens.get(&foo), "foo!");
+/// ```
+pub trait Lens<S: ?Sized> {
+    /// The type of the field.
+    type Target: ?Sized;
+
+    /// Borrow the field of the source.
+    fn get<'a>(&self, source: &'a S) -> &'a Self::Target;
+
+    /// Mutably borrow the field of the source.
+    fn get_mut<'a>(&self, source: &'a mut S) -> &'a mut Self::Target;
+}
+
+/// A [`Lens`] of the field marked with `N`, which is created by [`field_lens`].
+pub struct FieldLens<N>(PhantomData<fn() -> N>);
+
+impl<N> Clone for FieldLens<N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N> Copy for FieldLens<N> {}
+
+impl<N> fmt::Debug for FieldLens<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FieldLens")
+    }
+}
+
+/// Create a [`Lens`] of the field implemented by `#[get(field = N, mut)]`, like `field_lens::<Name>()`.
+#[inline(always)]
+pub fn field_lens<N>() -> FieldLens<N> {
+    FieldLens(PhantomData)
+}
+
+impl<S, N> Lens<S> for FieldLens<N>
+where
+    S: FieldMut<N> + ?Sized,
+{
+    type Target = S::Type;
+
+    #[inline(always)]
+    fn get<'a>(&self, source: &'a S) -> &'a S::Type {
+        source.field()
+    }
+
+    #[inline(always)]
+    fn get_mut<'a>(&self, source: &'a mut S) -> &'a mut S::Type {
+        source.field_mut()
+    }
+}
+
+/// A [`Lens`] of a getter and a mutable getter, which is created by [`lens`].
+#[derive(Clone, Copy, Debug)]
+pub struct FnLens<G, M> {
+    get: G,
+    get_mut: M,
+}
+
+/// Create a [`Lens`] from a getter and a mutable getter, like `lens(Foo::name, Foo::name_mut)`.
+#[inline(always)]
+pub fn lens<S, T, G, M>(get: G, get_mut: M) -> FnLens<G, M>
+where
+    S: ?Sized,
+    T: ?Sized,
+    G: for<'a> Fn(&'a S) -> &'a T,
+    M: for<'a> Fn(&'a mut S) -> &'a mut T,
+{
+    FnLens { get, get_mut }
+}
+
+impl<S, T, G, M> Lens<S> for FnLens<G, M>
+where
+    S: ?Sized,
+    T: ?Sized,
+    G: for<'a> Fn(&'a S) -> &'a T,
+    M: for<'a> Fn(&'a mut S) -> &'a mut T,
+{
+    type Target = T;
+
+    #[inline(always)]
+    fn get<'a>(&self, source: &'a S) -> &'a T {
+        (self.get)(source)
+    }
+
+    #[inline(always)]
+    fn get_mut<'a>(&self, source: &'a mut S) -> &'a mut T {
+        (self.get_mut)(source)
+    }
+}
@@ -1,34 +1,49 @@
 #![doc = include_str!("../README.md")]
+#![no_std]
 
-use proc_macro::TokenStream;
-use proc_macro_error::proc_macro_error;
-use syn::{parse_macro_input, DeriveInput};
+#[cfg(feature = "std")]
+extern crate std;
 
-mod args;
+mod error;
 mod field;
-mod getter;
-mod setter;
-mod ty;
-mod vis;
+mod lens;
+
+pub use self::error::SetError;
+pub use self::field::{Field, FieldMut, FieldName};
+pub use self::lens::{field_lens, lens, FieldLens, FnLens, Lens};
 
 #[doc = include_str!("../doc/getter.md")]
-#[proc_macro_derive(Getter, attributes(get))]
-#[proc_macro_error]
-pub fn getter(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
+#[doc(inline)]
+pub use getset2_derive::Getter;
 
-    let expanded = self::getter::expand(input);
+#[doc = include_str!("../doc/setter.md")]
+#[doc(inline)]
+pub use getset2_derive::Setter;
 
-    expanded.into()
-}
+#[doc = include_str!("../doc/builder.md")]
+#[doc(inline)]
+pub use getset2_derive::Builder;
 
-#[doc = include_str!("../doc/setter.md")]
-#[proc_macro_derive(Setter, attributes(set))]
-#[proc_macro_error]
-pub fn setter(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
+#[doc = include_str!("../doc/accessors.md")]
+#[doc(inline)]
+pub use getset2_derive::accessors;
+
+#[doc = include_str!("../doc/impl_accessors.md")]
+#[doc(inline)]
+pub use getset2_derive::impl_accessors;
+
+#[doc = include_str!("../doc/compat.md")]
+#[doc(inline)]
+pub use getset2_derive::Getters;
+
+/// Derive the `getset`-compatible mutable getters like `fn field_mut(&mut self) -> &mut T`, see [`Getters`](derive.Getters.html).
+#[doc(inline)]
+pub use getset2_derive::MutGetters;
 
-    let expanded = self::setter::expand(input);
+/// Derive the `getset`-compatible copy getters like `fn field(&self) -> T`, see [`Getters`](derive.Getters.html).
+#[doc(inline)]
+pub use getset2_derive::CopyGetters;
 
-    expanded.into()
-}
+/// Derive the `getset`-compatible setters like `fn set_field(&mut self, field: T) -> &mut Self`, see [`Getters`](derive.Getters.html).
+#[doc(inline)]
+pub use getset2_derive::Setters;
@@ -1,14 +0,0 @@
-mod args;
-mod context;
-mod expand;
-mod extend;
-mod field;
-mod gen;
-mod into;
-mod option;
-mod try_into;
-
-pub use self::args::{Extend, FieldArgs, StructArgs};
-pub use self::context::Context;
-pub use self::expand::expand;
-pub use self::field::Field;
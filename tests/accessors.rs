@@ -0,0 +1,64 @@
+mod foo {
+    #[getset2::accessors]
+    #[derive(Debug, Default, PartialEq)]
+    #[get(copy)]
+    pub struct Foo {
+        pub timeout: u64,
+
+        #[get(str)]
+        #[set(into)]
+        pub name: String,
+
+        #[getset(skip)]
+        pub secret: String,
+    }
+
+    #[getset2::accessors(get, builder, fields = pub(crate))]
+    #[derive(Debug, PartialEq)]
+    pub struct Bar {
+        #[get(copy)]
+        pub port: u16,
+    }
+
+    #[getset2::accessors(get)]
+    pub enum Addr {
+        Ip(String),
+        Unix(String),
+    }
+
+    impl Foo {
+        pub fn secret(&self) -> &str {
+            &self.secret
+        }
+    }
+}
+
+use foo::{Addr, Bar, Foo};
+
+#[test]
+fn accessors() {
+    let mut foo = Foo::default();
+
+    foo.set_timeout(10).set_name("foo");
+
+    assert_eq!(foo.timeout(), 10);
+    assert_eq!(foo.name(), "foo");
+    assert_eq!(foo.secret(), "");
+}
+
+#[test]
+fn accessors_args() {
+    let bar = Bar::builder().port(80).build().unwrap();
+
+    assert_eq!(bar.port(), 80);
+    assert_eq!(bar.port, 80);
+
+    let ip = Addr::Ip("127.0.0.1".to_string());
+    let unix = Addr::Unix("/tmp/sock".to_string());
+
+    assert!(ip.is_ip() && !ip.is_unix());
+    assert_eq!(ip.as_unix(), None);
+    assert_eq!(unix.as_ip(), None);
+    assert_eq!(ip.into_ip().ok(), Some("127.0.0.1".to_string()));
+    assert_eq!(unix.into_unix().ok(), Some("/tmp/sock".to_string()));
+}
@@ -0,0 +1,78 @@
+use getset2::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Foo {
+    name: String,
+    #[set(into)]
+    title: String,
+    #[set(try_into)]
+    port: u16,
+    #[set(opt)]
+    timeout: Option<u64>,
+    #[set(default = 3)]
+    retries: usize,
+    #[set(extend)]
+    tags: Vec<String>,
+    #[set(skip)]
+    skipped: bool,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(rename(PointMaker), prefix = "with")]
+pub struct Point<T>(T, #[set(default = T::default())] T)
+where
+    T: Default;
+
+#[test]
+fn builder() {
+    let foo = Foo::builder()
+        .name("foo".to_string())
+        .title("bar")
+        .port(8080)
+        .unwrap()
+        .timeout(30)
+        .append_tags("baz".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        foo,
+        Foo {
+            name: "foo".to_string(),
+            title: "bar".to_string(),
+            port: 8080,
+            timeout: Some(30),
+            retries: 3,
+            tags: vec!["baz".to_string()],
+            skipped: false,
+        }
+    );
+}
+
+#[test]
+fn builder_missing_fields() {
+    let mut builder = Foo::builder();
+
+    builder.title("bar").retries(5);
+
+    let err = builder.build().unwrap_err();
+
+    assert_eq!(err.missing_fields(), ["name", "port"]);
+    assert_eq!(err.to_string(), "missing required field(s): `name`, `port`");
+
+    assert!(builder.port(70000).is_err());
+}
+
+#[test]
+fn builder_unnamed_struct() {
+    let point = PointMaker::default().with_arg0(1).build().unwrap();
+
+    assert_eq!(point, Point(1, 0));
+    assert_eq!(
+        Point::<i32>::builder()
+            .build()
+            .unwrap_err()
+            .missing_fields(),
+        ["0"]
+    );
+}
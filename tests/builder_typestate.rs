@@ -0,0 +1,49 @@
+use getset2::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate)]
+pub struct Config<'a> {
+    host: &'a str,
+    #[set(try_into)]
+    port: u16,
+    #[set(opt)]
+    user: Option<&'a str>,
+    #[set(default = 30)]
+    timeout: u64,
+    #[set(extend)]
+    features: Vec<&'a str>,
+}
+
+#[test]
+fn builder_typestate() {
+    let config = Config::builder()
+        .port(8080)
+        .unwrap()
+        .append_features("tls")
+        .host("localhost")
+        .build();
+
+    assert_eq!(
+        config,
+        Config {
+            host: "localhost",
+            port: 8080,
+            user: None,
+            timeout: 30,
+            features: vec!["tls"],
+        }
+    );
+
+    let config = Config::builder()
+        .host("localhost")
+        .host("example.com")
+        .user("admin")
+        .timeout(10)
+        .port(443)
+        .unwrap()
+        .build();
+
+    assert_eq!(config.host, "example.com");
+    assert_eq!(config.user, Some("admin"));
+    assert_eq!(config.timeout, 10);
+}
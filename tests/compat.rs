@@ -0,0 +1,72 @@
+mod foo {
+    use getset2::{CopyGetters, Getter, Getters, MutGetters, Setter, Setters};
+
+    #[derive(Default, Getters, MutGetters, CopyGetters, Setters)]
+    #[getset(get = "pub", set = "pub")]
+    pub struct Foo {
+        name: String,
+
+        #[get_copy = "pub with_prefix"]
+        #[get_mut = "pub(crate)"]
+        #[set]
+        port: u16,
+
+        #[getset(get, get_mut = "pub")]
+        tags: Vec<String>,
+
+        #[getset(skip)]
+        secret: String,
+    }
+
+    impl Foo {
+        // the private accessors are only visible in the module
+        pub fn private_accessors(&mut self) -> usize {
+            self.set_port(1);
+            self.tags().len() + self.secret.len()
+        }
+    }
+
+    #[derive(Default, Getter, Setter)]
+    #[get(copy)]
+    #[getset(set = "pub")]
+    pub struct Bar {
+        #[get = "pub with_prefix"]
+        pub port: u16,
+
+        #[get(mut)]
+        #[get_copy = "pub(crate)"]
+        #[set(into)]
+        pub timeout: u64,
+
+        #[getset(skip)]
+        pub secret: String,
+    }
+}
+
+use foo::{Bar, Foo};
+
+#[test]
+fn compat_derives() {
+    let mut foo = Foo::default();
+
+    foo.set_name("foo".to_string());
+    *foo.port_mut() = 8080;
+    foo.tags_mut().push("bar".to_string());
+
+    assert_eq!(foo.name(), "foo");
+    assert_eq!(foo.get_port(), 8080);
+    assert_eq!(foo.private_accessors(), 1);
+    assert_eq!(foo.get_port(), 1);
+}
+
+#[test]
+fn compat_attributes() {
+    let mut bar = Bar::default();
+
+    bar.set_port(8080).set_timeout(10u8);
+    *bar.timeout_mut() += 1;
+
+    assert_eq!(bar.get_port(), 8080);
+    assert_eq!(bar.timeout(), 11);
+    assert!(bar.secret.is_empty());
+}
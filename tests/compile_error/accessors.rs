@@ -0,0 +1,19 @@
+mod foo {
+    #[getset2::accessors]
+    #[derive(Default)]
+    pub struct Foo {
+        #[get(copy)]
+        pub timeout: u64,
+    }
+}
+
+#[getset2::accessors(get, unknown)]
+pub struct Bar {
+    pub port: u16,
+}
+
+fn main() {
+    let mut foo = foo::Foo::default();
+
+    foo.timeout = 10;
+}
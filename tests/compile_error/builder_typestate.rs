@@ -0,0 +1,12 @@
+use getset2::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Config {
+    host: String,
+    port: u16,
+}
+
+fn main() {
+    let _ = Config::builder().host("localhost".to_string()).build();
+}
@@ -0,0 +1,35 @@
+use getset2::{Getter, Setter};
+
+#[derive(Default, Getter)]
+pub struct Foo {
+    #[get(rename(id))]
+    user_id: u32,
+
+    #[get(rename(id))]
+    group_id: u32,
+
+    #[get(mut)]
+    name: String,
+
+    name_mut: String,
+}
+
+#[derive(Default, Setter)]
+pub struct Bar {
+    #[set(extend)]
+    items: Vec<u32>,
+
+    #[set(prefix = "extend", rename(items))]
+    more_items: Vec<u32>,
+}
+
+fn main() {
+    // the methods of the first fields are still generated
+    let mut foo = Foo::default();
+
+    foo.name_mut().push('a');
+
+    let _ = (foo.id(), foo.name());
+
+    Bar::default().set_items(vec![]).extend_items([1]);
+}
@@ -0,0 +1,22 @@
+use getset2::{CopyGetters, Getters, Setters};
+
+#[derive(Getters, CopyGetters, Setters)]
+#[getset(set = "pub", skip)]
+pub struct Foo {
+    #[get = "pub(crate) prefix"]
+    name: String,
+
+    #[get_copy(pub)]
+    port: u16,
+
+    #[getset(get_copy, set = 1, unknown)]
+    timeout: u64,
+}
+
+#[derive(Getters)]
+#[get]
+pub enum Bar {
+    A,
+}
+
+fn main() {}
@@ -1,13 +1,7 @@
-use getset2::{Getter, Setter};
-
-#[derive(Getter)]
-pub enum Enum {
-    Foo,
-    Bar,
-}
+use getset2::Setter;
 
 #[derive(Setter)]
-pub enum Enum2 {
+pub enum Enum {
     Foo,
     Bar,
 }
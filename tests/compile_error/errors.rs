@@ -0,0 +1,31 @@
+use getset2::{Getter, Setter};
+
+#[derive(Default, Getter, Setter)]
+pub struct Foo {
+    #[get(opt)]
+    #[set(opt)]
+    count: usize,
+
+    #[get(str, unknown)]
+    #[set(extend)]
+    id: u32,
+
+    #[get(copy, clone)]
+    #[set(into, try_into)]
+    flag: bool,
+
+    #[get(slice)]
+    name: String,
+}
+
+fn main() {
+    // the accessors are still generated for the invalid attributes
+    let mut foo = Foo::default();
+
+    foo.set_count(1)
+        .set_id(2)
+        .set_flag(true)
+        .set_name("foo".to_string());
+
+    let _ = (foo.count(), foo.id(), foo.flag(), foo.name());
+}
@@ -0,0 +1,15 @@
+use getset2::Getter;
+
+#[derive(Getter)]
+pub struct Struct {
+    #[get(collection)] // #[get(collection)] is not allowed for a field that is not a collection
+    field: usize,
+}
+
+#[derive(Getter)]
+#[get(collection)] // #[get(collection)] is ignored when it applied to the structure
+pub struct Struct2 {
+    field: usize,
+}
+
+fn main() {}
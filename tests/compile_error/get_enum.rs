@@ -0,0 +1,18 @@
+use getset2::Getter;
+
+#[derive(Getter)]
+pub enum Enum {
+    Foo { field: usize },
+    Bar { field: String }, // the shared field should have the same type in all variants
+}
+
+#[derive(Getter)]
+pub enum Enum2 {
+    Foo { field: usize },
+    Bar {
+        #[get(skip)]
+        field: String,
+    },
+}
+
+fn main() {}
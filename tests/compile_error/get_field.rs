@@ -0,0 +1,23 @@
+use getset2::Getter;
+
+#[derive(Getter)]
+#[get(field_names)]
+pub struct Foo(u64);
+
+#[derive(Getter)]
+#[get(field_names)]
+pub enum Bar {
+    A { id: u64 },
+}
+
+pub struct Id;
+
+#[derive(Getter)]
+pub enum Baz {
+    A {
+        #[get(field = Id)]
+        id: u64,
+    },
+}
+
+fn main() {}
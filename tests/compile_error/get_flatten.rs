@@ -0,0 +1,15 @@
+use getset2::Getter;
+
+#[derive(Default, Getter)]
+pub struct NetConfig {
+    #[get(pub, copy)]
+    port: u16,
+}
+
+#[derive(Default, Getter)]
+pub struct Server {
+    #[get(flatten(port: u16 borrow))]
+    net: NetConfig,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use getset2::Getter;
+
+trait HasId {
+    fn id(&self) -> u64;
+}
+
+#[derive(Getter)]
+struct Foo {
+    #[get(impl_trait = HasId)]
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use getset2::Getter;
+
+#[derive(Getter)]
+#[get(inline(sometimes))]
+struct Foo {
+    #[get(copy)]
+    id: u64,
+}
+
+fn main() {
+    let foo = Foo { id: 1 };
+    let _: u64 = foo.id();
+}
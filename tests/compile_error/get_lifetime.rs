@@ -0,0 +1,16 @@
+use getset2::Getter;
+
+#[derive(Getter)]
+pub struct Struct<'a> {
+    #[get(lifetime)] // #[get(lifetime)] is not allowed for a field that is not a shared reference
+    field: &'a mut usize,
+}
+
+#[derive(Getter)]
+#[get(lifetime)] // #[get(lifetime)] is ignored for the fields which are not shared references
+pub struct Struct2<'a> {
+    field: usize,
+    ref_field: &'a usize,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use getset2::Getter;
+
+#[derive(Getter)]
+struct Foo {
+    #[get(copy, clone)]
+    count: usize,
+}
+
+#[derive(Getter)]
+#[get(copy)]
+struct Bar {
+    // the field mode replaces the `copy` of the structure
+    #[get(str)]
+    name: String,
+
+    #[get(str, bytes)]
+    data: String,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use getset2::Getter;
+
+pub struct Http {
+    timeout: u64,
+}
+
+#[derive(Getter)]
+#[get(path(timeout: http.timeout, copy))]
+pub struct Config {
+    http: Http,
+}
+
+#[derive(Getter)]
+#[get(path(timeout: http.timeout as u64, borrow))]
+pub struct Other {
+    http: Http,
+}
+
+#[derive(Getter)]
+#[get(path(timeout: http.timeout as u64))]
+pub enum Enum {
+    Foo { http: Http },
+}
+
+fn main() {}
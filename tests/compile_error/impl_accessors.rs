@@ -0,0 +1,15 @@
+mod pb {
+    pub struct Foo {
+        pub name: String,
+    }
+}
+
+getset2::impl_accessors! {
+    #[derive(Getter, Builder)]
+    impl pb::Foo {
+        #[get(str, unknown)]
+        name: String,
+    }
+}
+
+fn main() {}
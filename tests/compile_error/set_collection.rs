@@ -0,0 +1,15 @@
+use getset2::Setter;
+
+#[derive(Setter)]
+pub struct Struct {
+    #[set(collection)] // #[set(collection)] is not allowed for a field that is not a collection
+    field: usize,
+}
+
+#[derive(Setter)]
+pub struct Struct2 {
+    #[set(collection(list(usize)))] // unknown custom collection type
+    field: Vec<usize>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use getset2::Setter;
+
+#[derive(Default, Setter)]
+pub struct NetConfig {
+    port: u16,
+}
+
+#[derive(Default, Setter)]
+pub struct Server {
+    #[set(flatten(rename = "put", port: u16))]
+    net: NetConfig,
+}
+
+fn main() {}
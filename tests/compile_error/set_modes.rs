@@ -0,0 +1,29 @@
+use getset2::Setter;
+
+#[derive(Setter)]
+struct Foo {
+    #[set(into, try_into)]
+    count: usize,
+}
+
+#[derive(Setter)]
+struct Bar {
+    #[set(extend(usize, all))]
+    items: Vec<usize>,
+}
+
+#[derive(Setter)]
+#[set(into)]
+struct Baz {
+    // the field mode replaces the `into` of the structure
+    #[set(try_into)]
+    count: usize,
+}
+
+#[derive(Setter)]
+#[set(into, try_into)]
+struct Qux {
+    count: usize,
+}
+
+fn main() {}
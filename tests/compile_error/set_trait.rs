@@ -0,0 +1,9 @@
+use getset2::Setter;
+
+#[derive(Setter)]
+#[set(dyn)]
+struct Foo {
+    id: u64,
+}
+
+fn main() {}
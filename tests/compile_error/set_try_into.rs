@@ -6,6 +6,12 @@ pub struct Struct {
     field: f32,
 }
 
+#[derive(Setter)]
+#[set(try_into, error = boxed)]
+pub struct Other {
+    field: u8,
+}
+
 fn main() {
     let mut s = Struct { field: 3.14 };
 
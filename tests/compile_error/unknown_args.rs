@@ -0,0 +1,36 @@
+use getset2::{Getter, Setter};
+
+#[derive(Getter)]
+#[get(rename(name))] // #[get(rename)] can only be applied to the field
+pub struct Foo {
+    #[get(slcie)] // typo of `slice`
+    items: Vec<u8>,
+
+    #[get(into)] // `into` is an option of #[set(..)]
+    count: usize,
+
+    #[get(copy, slcie)] // `copy` still takes effect
+    id: u64,
+}
+
+#[derive(Setter)]
+#[set(extend)] // #[set(extend)] can only be applied to the field
+pub struct Bar {
+    #[set(trait = BarSetter)] // #[set(trait)] can only be applied to the structure
+    count: usize,
+
+    #[set(intoo)] // typo of `into`
+    name: String,
+
+    #[set(copy, foobar)]
+    id: u32,
+}
+
+fn main() {
+    let foo = Foo {
+        items: vec![],
+        count: 0,
+        id: 1,
+    };
+    let _: u64 = foo.id();
+}
@@ -3,3 +3,7 @@ use doc_comment::doctest;
 doctest!("../README.md", readme);
 doctest!("../doc/getter.md", getter);
 doctest!("../doc/setter.md", setter);
+doctest!("../doc/builder.md", builder);
+doctest!("../doc/compat.md", compat);
+doctest!("../doc/impl_accessors.md", impl_accessors);
+doctest!("../doc/accessors.md", accessors);
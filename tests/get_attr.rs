@@ -5,7 +5,7 @@ use getset2::Getter;
 struct Foo {
     #[doc = "test"]
     #[get(attr(rustfmt::skip))]
-    #[get(attr(clippy::cyclomatic_complexity = "100"))]
+    #[get(attr(clippy::cognitive_complexity = "100"))]
     bar: usize,
 }
 
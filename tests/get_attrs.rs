@@ -5,7 +5,7 @@ use getset2::Getter;
 struct Foo {
     #[doc = "test"]
         #[rustfmt::skip]
-        #[clippy::cyclomatic_complexity = "100"]
+        #[clippy::cognitive_complexity = "100"]
         bar: usize,
 }
 
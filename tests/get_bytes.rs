@@ -1,6 +1,9 @@
 use std::ffi::{CStr, CString};
-#[cfg(target_os = "unix")]
-use std::{ffi::OsStr, os::unix::ffi::OsStrExt, OsString};
+#[cfg(unix)]
+use std::{
+    ffi::{OsStr, OsString},
+    os::unix::ffi::OsStrExt,
+};
 
 use getset2::Getter;
 
@@ -30,11 +33,11 @@ struct Foo<'a> {
     #[get(bytes(Foobar::as_bytes))]
     custom_field: Foobar,
 
-    #[cfg(target_os = "unix")]
+    #[cfg(unix)]
     #[get(bytes)]
     os_str_field: &'a OsStr,
 
-    #[cfg(target_os = "unix")]
+    #[cfg(unix)]
     #[get(bytes)]
     os_string_field: OsString,
 }
@@ -59,9 +62,9 @@ fn get_bytes() {
         vec_field: vec![1, 2, 3],
         array_field: [4, 5, 6],
         custom_field: Foobar(vec![7, 8, 9]),
-        #[cfg(target_os = "unix")]
+        #[cfg(unix)]
         os_str_field: OsStr::new("os_str"),
-        #[cfg(target_os = "unix")]
+        #[cfg(unix)]
         os_string_field: OsString::from("os_string"),
     };
 
@@ -73,8 +76,8 @@ fn get_bytes() {
     assert_eq!(foo.vec_field(), &[1, 2, 3]);
     assert_eq!(foo.array_field(), &[4, 5, 6]);
     assert_eq!(foo.custom_field(), &[7, 8, 9]);
-    #[cfg(target_os = "unix")]
+    #[cfg(unix)]
     assert_eq!(foo.os_str_field(), b"os_str");
-    #[cfg(target_os = "unix")]
+    #[cfg(unix)]
     assert_eq!(foo.os_string_field(), b"os_string");
 }
@@ -0,0 +1,73 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use getset2::Getter;
+
+#[derive(Default, Getter)]
+#[get(pub, collection)]
+pub struct Foo {
+    #[get(mut)]
+    vec_field: Vec<String>,
+    deque_field: VecDeque<usize>,
+    #[get(mut)]
+    map_field: HashMap<String, usize>,
+    btree_map_field: BTreeMap<usize, String>,
+    set_field: HashSet<String>,
+    btree_set_field: BTreeSet<usize>,
+    #[get(collection(false))]
+    plain_field: Vec<usize>,
+}
+
+#[test]
+fn get_collection() {
+    let mut foo = Foo {
+        vec_field: vec!["foo".to_string(), "bar".to_string()],
+        deque_field: VecDeque::from([1, 2, 3]),
+        map_field: HashMap::from([("foo".to_string(), 1)]),
+        btree_map_field: BTreeMap::from([(1, "foo".to_string()), (2, "bar".to_string())]),
+        set_field: HashSet::from(["foo".to_string()]),
+        btree_set_field: BTreeSet::from([1, 2]),
+        plain_field: vec![1],
+    };
+
+    assert_eq!(foo.vec_field_len(), 2);
+    assert!(!foo.vec_field_is_empty());
+    assert_eq!(foo.vec_field_get(1).unwrap(), "bar");
+    assert!(foo.vec_field_contains("foo"));
+    assert!(!foo.vec_field_contains("baz"));
+    assert_eq!(foo.vec_field_iter().collect::<Vec<_>>(), ["foo", "bar"]);
+
+    foo.vec_field_get_mut(0).unwrap().push('!');
+    foo.vec_field_iter_mut().for_each(|s| s.push('?'));
+
+    assert_eq!(foo.vec_field_iter().collect::<Vec<_>>(), ["foo!?", "bar?"]);
+
+    assert_eq!(foo.deque_field_get(2), Some(&3));
+    assert!(foo.deque_field_contains(&1));
+    assert_eq!(foo.deque_field_iter().sum::<usize>(), 6);
+
+    assert_eq!(foo.map_field_get("foo"), Some(&1));
+    assert!(foo.map_field_contains("foo"));
+    assert!(!foo.map_field_contains("bar"));
+
+    *foo.map_field_get_mut("foo").unwrap() += 1;
+    foo.map_field_values_mut().for_each(|v| *v *= 10);
+    foo.map_field_iter_mut().for_each(|(_, v)| *v += 1);
+
+    assert_eq!(foo.map_field_values().collect::<Vec<_>>(), [&21]);
+    assert_eq!(foo.map_field_keys().collect::<Vec<_>>(), ["foo"]);
+
+    assert_eq!(foo.btree_map_field_len(), 2);
+    assert_eq!(foo.btree_map_field_get(&2).unwrap(), "bar");
+    assert_eq!(foo.btree_map_field_keys().collect::<Vec<_>>(), [&1, &2]);
+    assert_eq!(
+        foo.btree_map_field_iter().collect::<Vec<_>>(),
+        [(&1, &"foo".to_string()), (&2, &"bar".to_string())]
+    );
+
+    assert!(foo.set_field_contains("foo"));
+    assert_eq!(foo.set_field_get("foo").unwrap(), "foo");
+    assert!(foo.btree_set_field_contains(&2));
+    assert_eq!(foo.btree_set_field_iter().collect::<Vec<_>>(), [&1, &2]);
+
+    assert_eq!(foo.plain_field(), &[1]);
+}
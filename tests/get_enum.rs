@@ -0,0 +1,53 @@
+use getset2::Getter;
+
+#[derive(Getter)]
+pub enum Event<'a> {
+    Created {
+        /// `pub fn id(&self) -> u64`
+        #[get(copy)]
+        id: u64,
+
+        /// `pub fn name(&self) -> &str`
+        /// `pub fn name_mut(&mut self) -> &mut String`
+        #[get(str, mut)]
+        name: String,
+
+        /// `pub fn tags(&self) -> Option<&[&'a str]>`
+        #[get(slice)]
+        tags: Vec<&'a str>,
+    },
+    Renamed {
+        id: u64,
+        name: String,
+        from: String,
+    },
+    Deleted {
+        id: u64,
+        name: String,
+    },
+}
+
+#[test]
+fn get_enum() {
+    let mut created = Event::Created {
+        id: 1,
+        name: "foo".to_owned(),
+        tags: vec!["bar"],
+    };
+    let deleted = Event::Deleted {
+        id: 2,
+        name: "baz".to_owned(),
+    };
+
+    assert_eq!(created.id(), 1);
+    assert_eq!(created.name(), "foo");
+    assert_eq!(created.tags(), Some(&["bar"][..]));
+    assert_eq!(created.from(), None);
+
+    created.name_mut().push_str("bar");
+    assert_eq!(created.name(), "foobar");
+
+    assert_eq!(deleted.id(), 2);
+    assert_eq!(deleted.name(), "baz");
+    assert_eq!(deleted.tags(), None);
+}
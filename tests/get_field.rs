@@ -0,0 +1,76 @@
+use getset2::{field_lens, Field, FieldMut, FieldName, Getter, Lens};
+
+pub struct Name;
+
+pub struct Id;
+
+#[derive(Default, Getter)]
+#[get(pub, field_names)]
+pub struct Foo {
+    #[get(field = Name, mut)]
+    name: String,
+
+    #[get(field = Id, copy)]
+    user_id: u64,
+}
+
+#[derive(Default, Getter)]
+#[get(pub, field_names(BarFields))]
+pub struct Bar<T> {
+    #[get(field = Name, mut)]
+    title: String,
+
+    #[get(field = Id)]
+    value: T,
+}
+
+mod rt {
+    pub use getset2 as getset;
+}
+
+#[derive(Default, Getter)]
+#[get(pub, crate = crate::rt::getset)]
+pub struct Baz(#[get(field = Name)] String);
+
+fn name<T: Field<Name, Type = String>>(value: &T) -> &str {
+    value.field()
+}
+
+fn shout<T: FieldMut<Name, Type = String>>(value: &mut T) {
+    value.field_mut().make_ascii_uppercase();
+}
+
+#[test]
+fn get_field() {
+    let mut foo = Foo {
+        name: "foo".to_string(),
+        user_id: 1,
+    };
+    let mut bar = Bar {
+        title: "bar".to_string(),
+        value: 2u8,
+    };
+
+    shout(&mut foo);
+    shout(&mut bar);
+
+    assert_eq!(name(&foo), "FOO");
+    assert_eq!(name(&bar), "BAR");
+    assert_eq!(name(&Baz("baz".to_string())), "baz");
+    assert_eq!(*Field::<Id>::field(&foo), 1);
+    assert_eq!(*Field::<Id>::field(&bar), 2);
+
+    field_lens::<Name>().get_mut(&mut foo).push('!');
+
+    assert_eq!(foo.name(), "FOO!");
+    assert_eq!(foo.user_id(), 1);
+}
+
+#[test]
+fn get_field_names() {
+    assert_eq!(FooField::ALL, &[FooField::Name, FooField::UserId]);
+    assert_eq!(FooField::UserId.name(), "user_id");
+    assert_eq!(FooField::from_name("name"), Some(FooField::Name));
+    assert_eq!(FooField::from_name("id"), None);
+    assert_eq!(BarFields::ALL, &[BarFields::Title, BarFields::Value]);
+}
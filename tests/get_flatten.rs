@@ -0,0 +1,38 @@
+use getset2::Getter;
+
+#[derive(Default, Getter)]
+#[get(pub)]
+pub struct NetConfig {
+    #[get(copy, mut)]
+    port: u16,
+    #[get(str)]
+    host: String,
+}
+
+#[derive(Default, Getter)]
+#[get(pub, prefix = "get")]
+pub struct Server {
+    /// The network config.
+    #[get(flatten(port: u16 copy mut, host: str))]
+    net: NetConfig,
+
+    #[get(copy)]
+    workers: usize,
+}
+
+#[test]
+fn get_flatten() {
+    let mut server = Server {
+        net: NetConfig {
+            port: 80,
+            host: "localhost".to_string(),
+        },
+        workers: 4,
+    };
+
+    *server.get_port_mut() += 8000;
+
+    assert_eq!(server.get_port(), 8080);
+    assert_eq!(server.get_host(), "localhost");
+    assert_eq!(server.get_workers(), 4);
+}
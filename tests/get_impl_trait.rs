@@ -0,0 +1,80 @@
+use getset2::Getter;
+
+mod domain {
+    pub trait HasId {
+        fn id(&self) -> u64;
+    }
+
+    pub trait Named {
+        fn name(&self) -> &str;
+        fn name_mut(&mut self) -> &mut String;
+    }
+}
+
+use domain::{HasId, Named};
+
+#[derive(Default, Getter)]
+#[get(pub)]
+struct Foo {
+    #[get(copy, impl_trait = domain::HasId)]
+    id: u64,
+
+    #[get(str, mut, impl_trait = crate::domain::Named)]
+    name: String,
+
+    #[get(copy)]
+    count: usize,
+}
+
+#[derive(Default, Getter)]
+#[get(copy, impl_trait = HasId)]
+struct Bar<T: Copy> {
+    id: u64,
+    #[get(impl_trait = AsValue<T>)]
+    value: T,
+}
+
+trait AsValue<T> {
+    fn value(&self) -> T;
+}
+
+#[derive(Getter)]
+#[get(pub, copy)]
+pub enum Baz {
+    Foo {
+        #[get(impl_trait = HasId)]
+        id: u64,
+    },
+    Bar {
+        id: u64,
+        flag: bool,
+    },
+}
+
+fn ids(items: &[&dyn HasId]) -> Vec<u64> {
+    items.iter().map(|item| item.id()).collect()
+}
+
+#[test]
+fn get_impl_trait() {
+    let mut foo = Foo {
+        id: 1,
+        name: "foo".to_string(),
+        count: 3,
+    };
+
+    foo.name_mut().push_str("bar");
+
+    assert_eq!(foo.name(), "foobar");
+    assert_eq!(foo.count(), 3);
+
+    let bar = Bar { id: 2, value: 'x' };
+
+    assert_eq!(bar.value(), 'x');
+
+    let baz = Baz::Bar { id: 3, flag: true };
+
+    assert!(baz.is_bar());
+    assert_eq!(Baz::Foo { id: 4 }.flag(), None);
+    assert_eq!(ids(&[&foo, &bar, &baz]), [1, 2, 3]);
+}
@@ -0,0 +1,103 @@
+use getset2::Getter;
+
+pub struct Node {
+    id: u32,
+}
+
+#[derive(Getter)]
+#[get(lifetime)]
+pub struct Token<'a> {
+    /// `fn text(&self) -> &'a str`
+    text: &'a str,
+
+    /// `fn bytes(&self) -> &'a [u8]`
+    bytes: &'a [u8],
+
+    /// `fn node(&self) -> Option<&'a Node>`
+    node: Option<&'a Node>,
+
+    /// `fn name(&self) -> &'a str`
+    #[get(str)]
+    name: &'a String,
+
+    /// `fn label(&self) -> Option<&'a str>`
+    #[get(opt, str)]
+    label: Option<&'a String>,
+
+    /// `fn parent(&self) -> Option<&'a Node>`
+    #[get(opt, copy)]
+    parent: Option<&'a Node>,
+
+    /// `fn len(&self) -> &usize`, the struct-level `lifetime` skips the owned fields
+    len: usize,
+}
+
+#[derive(Getter)]
+pub struct Span<'a> {
+    /// `fn source(&self) -> &'a str`
+    #[get(copy, lifetime)]
+    source: &'a str,
+
+    /// `fn start(&self) -> &&'a usize`
+    start: &'a usize,
+}
+
+#[derive(Getter)]
+#[get(lifetime, trait = ExprAccessors)]
+pub enum Expr<'a> {
+    Ident {
+        /// `fn name(&self) -> Option<&'a str>`
+        name: &'a str,
+    },
+    Literal {
+        value: u64,
+    },
+}
+
+fn source_of(source: &str) -> &str {
+    let span = Span { source, start: &0 };
+
+    // the value outlives the borrow of the span
+    span.source()
+}
+
+#[test]
+fn get_lifetime() {
+    let node = Node { id: 1 };
+    let name = "foo".to_string();
+
+    let token = Token {
+        text: "foo bar",
+        bytes: b"foo",
+        node: Some(&node),
+        name: &name,
+        label: Some(&name),
+        parent: Some(&node),
+        len: 7,
+    };
+
+    assert_eq!(token.text(), "foo bar");
+    assert_eq!(token.bytes(), b"foo");
+    assert_eq!(token.node().map(|node| node.id), Some(1));
+    assert_eq!(token.name(), "foo");
+    assert_eq!(token.label(), Some("foo"));
+    assert_eq!(token.parent().map(|node| node.id), Some(1));
+    assert_eq!(token.len(), &7);
+
+    assert_eq!(source_of("baz"), "baz");
+
+    let span = Span {
+        source: "foo",
+        start: &0,
+    };
+
+    assert_eq!(span.source(), "foo");
+    assert_eq!(span.start(), &&0);
+
+    let ident = Expr::Ident { name: "foo" };
+    let literal = Expr::Literal { value: 1 };
+
+    assert_eq!(ident.name(), Some("foo"));
+    assert_eq!(ExprAccessors::name(&literal), None);
+    assert_eq!(literal.value(), Some(&1));
+}
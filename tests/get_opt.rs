@@ -8,6 +8,60 @@ struct Foo {
     /// `pub fn option_field_mut(&mut self) -> Option<&mut HashMap<String, usize>>`
     #[get(opt, mut)]
     pub option_field: Option<HashMap<String, usize>>,
+
+    /// `pub fn has_utility_field(&self) -> bool`
+    /// `pub fn utility_field_or_insert_with<F>(&mut self, f: F) -> &mut Vec<usize>`
+    /// `pub fn utility_field_or_default(&mut self) -> &mut Vec<usize>`
+    #[get(opt(has, or_insert, or_default))]
+    pub utility_field: Option<Vec<usize>>,
+}
+
+#[derive(Default, Getter)]
+#[get(opt(has))]
+struct Bar {
+    pub first: Option<usize>,
+    #[get(opt(false))]
+    pub second: Option<usize>,
+}
+
+#[derive(Default, Getter)]
+#[get(prefix = "get", suffix = "opt", opt(has, or_insert, or_default))]
+struct Qux {
+    /// `pub fn get_has_value_opt(&self) -> bool`
+    /// `pub fn get_value_opt_or_insert_with<F>(&mut self, f: F) -> &mut usize`
+    /// `pub fn get_value_opt_or_default(&mut self) -> &mut usize`
+    pub value: Option<usize>,
+}
+
+#[derive(Default, Getter)]
+#[get(opt)]
+struct Baz {
+    /// `pub fn name(&self) -> Option<&str>`
+    /// `pub fn name_mut(&mut self) -> Option<&mut str>`
+    #[get(str, mut_str)]
+    pub name: Option<String>,
+
+    /// `pub fn items(&self) -> Option<&[usize]>`
+    /// `pub fn items_mut(&mut self) -> Option<&mut [usize]>`
+    #[get(slice, mut_slice)]
+    pub items: Option<Vec<usize>>,
+
+    /// `pub fn label(&self) -> Option<String>`
+    #[get(clone)]
+    pub label: Option<String>,
+
+    /// `pub fn count(&self) -> Option<usize>`
+    #[get(copy)]
+    pub count: Option<usize>,
+
+    /// `pub fn data(&self) -> Option<&[u8]>`
+    #[get(bytes)]
+    pub data: Option<Vec<u8>>,
+
+    /// `pub fn key(&self) -> Option<&str>`
+    /// `pub fn key_mut(&mut self) -> Option<&mut str>`
+    #[get(borrow(str), borrow_mut(str))]
+    pub key: Option<String>,
 }
 
 #[test]
@@ -22,3 +76,69 @@ fn get_opt() {
 
     assert_eq!(foo.option_field().unwrap().get("foo"), Some(&123));
 }
+
+#[test]
+fn get_opt_utilities() {
+    let mut foo = Foo::default();
+
+    assert!(!foo.has_utility_field());
+    assert_eq!(foo.utility_field_or_insert_with(|| vec![1]), &[1]);
+    assert!(foo.has_utility_field());
+
+    foo.utility_field_or_default().push(2);
+
+    assert_eq!(foo.utility_field(), Some(&vec![1, 2]));
+
+    foo.utility_field = None;
+
+    assert!(foo.utility_field_or_default().is_empty());
+
+    let bar = Bar {
+        first: Some(1),
+        ..Default::default()
+    };
+
+    assert!(bar.has_first());
+    assert_eq!(bar.first(), Some(&1));
+    assert_eq!(bar.second(), &None);
+}
+
+#[test]
+fn get_opt_utilities_with_prefix() {
+    let mut qux = Qux::default();
+
+    assert!(!qux.get_has_value_opt());
+    assert_eq!(*qux.get_value_opt_or_insert_with(|| 1), 1);
+    assert!(qux.get_has_value_opt());
+
+    qux.value = None;
+    *qux.get_value_opt_or_default() += 2;
+
+    assert_eq!(qux.get_value_opt(), Some(&2));
+}
+
+#[test]
+fn get_opt_composed() {
+    let mut baz = Baz::default();
+
+    assert_eq!(baz.name(), None);
+    assert_eq!(baz.items(), None);
+
+    baz.name = Some("foo".to_string());
+    baz.items = Some(vec![1, 2]);
+    baz.label = Some("bar".to_string());
+    baz.count = Some(3);
+    baz.data = Some(b"baz".to_vec());
+    baz.key = Some("key".to_string());
+
+    baz.name_mut().unwrap().make_ascii_uppercase();
+    baz.items_mut().unwrap()[0] = 0;
+
+    assert_eq!(baz.name(), Some("FOO"));
+    assert_eq!(baz.items(), Some(&[0, 2][..]));
+    assert_eq!(baz.label(), Some("bar".to_string()));
+    assert_eq!(baz.count(), Some(3));
+    assert_eq!(baz.data(), Some(&b"baz"[..]));
+    assert_eq!(baz.key(), Some("key"));
+    assert_eq!(baz.key_mut().map(|key| key.len()), Some(3));
+}
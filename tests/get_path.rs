@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use getset2::Getter;
+
+#[derive(Default)]
+pub struct Http {
+    timeout: Duration,
+    user_agent: String,
+}
+
+#[derive(Default)]
+pub struct Net {
+    http: Http,
+    proxy: Option<Http>,
+}
+
+#[derive(Default)]
+pub struct Auth {
+    token: String,
+    expires: Option<u64>,
+}
+
+#[derive(Default, Getter)]
+#[get(pub)]
+#[get(path(timeout: net.http.timeout as Duration, copy))]
+#[get(path(user_agent: net.http.user_agent, str, http: net.http as Http))]
+#[get(path(proxy_timeout: net.proxy?.timeout as Duration, copy))]
+#[get(path(token: auth?.token, str))]
+#[get(path(expires: auth?.expires? as u64, copy))]
+#[get(path(first: pair.0 as String, clone))]
+pub struct Config {
+    net: Net,
+    auth: Option<Auth>,
+    pair: (String, usize),
+}
+
+#[test]
+fn get_path() {
+    let mut config = Config {
+        net: Net {
+            http: Http {
+                timeout: Duration::from_secs(30),
+                user_agent: "getset2".to_string(),
+            },
+            proxy: None,
+        },
+        auth: None,
+        pair: ("foo".to_string(), 1),
+    };
+
+    assert_eq!(config.timeout(), Duration::from_secs(30));
+    assert_eq!(config.user_agent(), "getset2");
+    assert_eq!(config.http().timeout, Duration::from_secs(30));
+    assert_eq!(config.proxy_timeout(), None);
+    assert_eq!(config.token(), None);
+    assert_eq!(config.expires(), None);
+    assert_eq!(config.first(), "foo");
+
+    config.auth = Some(Auth {
+        token: "secret".to_string(),
+        expires: Some(3600),
+    });
+
+    assert_eq!(config.token(), Some("secret"));
+    assert_eq!(config.expires(), Some(3600));
+}
@@ -15,9 +15,11 @@ struct Foo {
 
 #[test]
 fn get_slice() {
-    let mut foo = Foo::default();
+    let mut foo = Foo {
+        vec_field: vec![0; 3],
+        ..Default::default()
+    };
 
-    foo.vec_field = vec![0; 3];
     foo.vec_field_mut().copy_from_slice(&[1, 2, 3]);
 
     foo.array_field_mut()
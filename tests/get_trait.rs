@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use getset2::Getter;
+
+#[derive(Default, Getter)]
+#[get(pub, trait = FooAccessors)]
+pub struct Foo<T> {
+    #[get(copy, const)]
+    id: usize,
+    #[get(str, mut)]
+    name: String,
+    #[get(collection)]
+    scores: HashMap<String, T>,
+    value: T,
+}
+
+struct MockFoo;
+
+impl FooAccessors<u32> for MockFoo {
+    fn id(&self) -> usize {
+        42
+    }
+
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    fn name_mut(&mut self) -> &mut String {
+        unimplemented!()
+    }
+
+    fn scores_len(&self) -> usize {
+        0
+    }
+
+    fn scores_is_empty(&self) -> bool {
+        true
+    }
+
+    fn scores_iter(&self) -> Box<dyn Iterator<Item = (&String, &u32)> + '_> {
+        Box::new(std::iter::empty())
+    }
+
+    fn scores_get<Q>(&self, _key: &Q) -> Option<&u32>
+    where
+        String: std::borrow::Borrow<Q> + std::hash::Hash + Eq,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        None
+    }
+
+    fn scores_contains<Q>(&self, _key: &Q) -> bool
+    where
+        String: std::borrow::Borrow<Q> + std::hash::Hash + Eq,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        false
+    }
+
+    fn scores_keys(&self) -> Box<dyn Iterator<Item = &String> + '_> {
+        Box::new(std::iter::empty())
+    }
+
+    fn scores_values(&self) -> Box<dyn Iterator<Item = &u32> + '_> {
+        Box::new(std::iter::empty())
+    }
+
+    fn value(&self) -> &u32 {
+        &0
+    }
+}
+
+fn describe(target: &dyn FooAccessors<u32>) -> String {
+    format!("{}#{}: {}", target.name(), target.id(), target.scores_len())
+}
+
+#[test]
+fn get_trait() {
+    let mut foo = Foo {
+        id: 1,
+        name: "foo".to_string(),
+        scores: HashMap::from([("bar".to_string(), 2)]),
+        value: 3,
+    };
+
+    foo.name_mut().push('!');
+
+    assert_eq!(describe(&foo), "foo!#1: 1");
+    assert_eq!(describe(&MockFoo), "mock#42: 0");
+    assert_eq!(foo.scores_get("bar"), Some(&2));
+    assert_eq!(foo.scores_keys().collect::<Vec<_>>(), ["bar"]);
+    assert_eq!(*FooAccessors::value(&foo), 3);
+}
@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+
+use getset2::Getter;
+
+#[derive(Debug, PartialEq, Getter)]
+#[get(mut)]
+pub enum Source {
+    /// `pub fn is_stdin(&self) -> bool`
+    Stdin,
+
+    /// `pub fn is_file(&self) -> bool`
+    /// `pub fn as_file(&self) -> Option<&PathBuf>`
+    /// `pub fn as_file_mut(&mut self) -> Option<&mut PathBuf>`
+    /// `pub fn into_file(self) -> Result<PathBuf, Self>`
+    File(PathBuf),
+
+    /// `pub fn is_tcp_stream(&self) -> bool`
+    /// `pub fn as_tcp_stream(&self) -> Option<(&String, &u16)>`
+    /// `pub fn as_tcp_stream_mut(&mut self) -> Option<(&mut String, &mut u16)>`
+    /// `pub fn into_tcp_stream(self) -> Result<(String, u16), Self>`
+    TcpStream { host: String, port: u16 },
+
+    /// `pub(crate) fn is_sock(&self) -> bool`
+    /// `pub(crate) fn as_sock(&self) -> Option<&PathBuf>`
+    /// `pub(crate) fn into_sock(self) -> Result<PathBuf, Self>`
+    #[get(pub(crate), rename(sock), mut(false))]
+    UnixSocket(PathBuf),
+
+    /// `pub const fn is_url_source(&self) -> bool`
+    /// `pub const fn as_url_source(&self) -> Option<&String>`
+    #[get(const, suffix = "source")]
+    Url(String),
+
+    /// `pub fn get_is_pipe(&self) -> bool`
+    /// `pub fn get_as_pipe(&self) -> Option<&String>`
+    /// `pub fn get_into_pipe(self) -> Result<String, Self>`
+    #[get(prefix = "get", mut(false))]
+    Pipe(String),
+
+    #[get(skip)]
+    Other,
+}
+
+#[test]
+fn get_variant() {
+    let mut src = Source::File(PathBuf::from("/tmp"));
+
+    assert!(src.is_file());
+    assert!(!src.is_stdin());
+    assert_eq!(src.as_file(), Some(&PathBuf::from("/tmp")));
+    assert_eq!(src.as_tcp_stream(), None);
+
+    src.as_file_mut().unwrap().push("foo");
+    assert_eq!(src.into_file(), Ok(PathBuf::from("/tmp/foo")));
+
+    let mut src = Source::TcpStream {
+        host: "localhost".to_owned(),
+        port: 80,
+    };
+
+    *src.as_tcp_stream_mut().unwrap().1 = 8080;
+    assert_eq!(src.as_tcp_stream(), Some((&"localhost".to_owned(), &8080)));
+    assert_eq!(
+        src.into_file(),
+        Err(Source::TcpStream {
+            host: "localhost".to_owned(),
+            port: 8080,
+        })
+    );
+
+    let src = Source::UnixSocket(PathBuf::from("/var/run/sock"));
+
+    assert!(src.is_sock());
+    assert_eq!(src.as_sock(), Some(&PathBuf::from("/var/run/sock")));
+    assert_eq!(src.into_sock(), Ok(PathBuf::from("/var/run/sock")));
+
+    let src = Source::Url("http://localhost".to_owned());
+
+    assert!(src.is_url_source());
+    assert_eq!(src.as_url_source().unwrap(), "http://localhost");
+    assert!(Source::Stdin.is_stdin());
+
+    let src = Source::Pipe("foo".to_owned());
+
+    assert!(src.get_is_pipe());
+    assert_eq!(src.get_as_pipe().unwrap(), "foo");
+    assert_eq!(src.get_into_pipe(), Ok("foo".to_owned()));
+}
+
+#[derive(Getter)]
+pub enum Single {
+    Value(usize),
+}
+
+#[test]
+fn get_single_variant() {
+    assert_eq!(Single::Value(123).into_value().ok(), Some(123));
+}
@@ -0,0 +1,60 @@
+mod pb {
+    #[derive(Default)]
+    pub struct Request {
+        pub name: String,
+        pub deadline: Option<u64>,
+        pub tags: Vec<String>,
+    }
+
+    #[derive(Default)]
+    pub struct Wrapper<T> {
+        pub inner: T,
+    }
+}
+
+mod ext {
+    getset2::impl_accessors! {
+        #[get(pub)]
+        #[set(pub, trait = RequestExt)]
+        impl crate::pb::Request {
+            #[get(str)]
+            #[set(into)]
+            name: String,
+
+            #[get(opt, copy)]
+            #[set(opt)]
+            deadline: Option<u64>,
+
+            #[get(slice)]
+            #[set(extend)]
+            tags: Vec<String>,
+        }
+
+        #[derive(Getter)]
+        #[get(pub, mut)]
+        impl<T> crate::pb::Wrapper<T> where T: Default {
+            inner: T,
+        }
+    }
+}
+
+use ext::{RequestExt, RequestGetters, WrapperGetters};
+
+#[test]
+fn impl_accessors() {
+    let mut req = pb::Request::default();
+
+    req.set_name("foo")
+        .set_deadline(10)
+        .extend_tags(["bar".to_string()]);
+
+    assert_eq!(req.name(), "foo");
+    assert_eq!(req.deadline(), Some(10));
+    assert_eq!(req.tags(), ["bar"]);
+
+    let mut wrapper = pb::Wrapper::<u8>::default();
+
+    *wrapper.inner_mut() = 1;
+
+    assert_eq!(*wrapper.inner(), 1);
+}
@@ -0,0 +1,70 @@
+#![no_std]
+
+extern crate alloc;
+// the test harness needs `std`, which is linked without `::std` in scope
+extern crate std as _;
+
+use alloc::{collections::BTreeMap, string::String, string::ToString, vec, vec::Vec};
+
+use getset2::{Builder, Getter, Setter};
+
+#[derive(Builder, Debug, Default, Getter, PartialEq, Setter)]
+#[get(pub, trait = FooAccessors, no_std)]
+#[set(pub, no_std)]
+#[builder(no_std)]
+pub struct Foo {
+    #[get(copy)]
+    #[set(try_into)]
+    id: u8,
+    #[get(str, mut)]
+    #[set(into)]
+    name: String,
+    #[get(slice)]
+    #[set(extend)]
+    tags: Vec<u32>,
+    #[get(opt(or_default), copy)]
+    #[set(opt(take))]
+    limit: Option<u32>,
+    #[get(collection)]
+    #[set(collection)]
+    scores: BTreeMap<String, u32>,
+}
+
+#[test]
+fn no_std() {
+    let mut foo = Foo::default();
+
+    foo.set_id(7u32)
+        .unwrap()
+        .set_name("foo")
+        .extend_tags([1, 2])
+        .set_limit(10);
+    foo.insert_scores(String::from("a"), 1);
+    foo.scores_entry(String::from("b")).or_insert(2);
+    foo.name_mut().push('!');
+
+    assert_eq!(foo.id(), 7);
+    assert_eq!(foo.name(), "foo!");
+    assert_eq!(foo.tags(), &[1, 2]);
+    assert_eq!(foo.limit(), Some(10));
+    assert_eq!(foo.take_limit(), Some(10));
+    assert_eq!(FooAccessors::scores_iter(&foo).count(), 2);
+
+    let built = Foo::builder()
+        .id(7u32)
+        .unwrap()
+        .name(String::from("foo!"))
+        .tags(vec![1, 2])
+        .scores(foo.scores.clone())
+        .build()
+        .unwrap();
+
+    assert_eq!(built, foo);
+    let err = Foo::builder().build().unwrap_err();
+
+    assert_eq!(err.missing_fields(), &["id", "name", "scores"]);
+    assert_eq!(
+        err.to_string(),
+        "missing required field(s): `id`, `name`, `scores`"
+    );
+}
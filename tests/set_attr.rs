@@ -5,7 +5,7 @@ use getset2::{Getter, Setter};
 struct Foo {
     #[doc = "test"]
     #[set(attr(rustfmt::skip))]
-    #[set(attr(clippy::cyclomatic_complexity = "100"))]
+    #[set(attr(clippy::cognitive_complexity = "100"))]
     bar: usize,
 }
 
@@ -6,7 +6,7 @@ use getset2::{Getter, Setter};
 struct Foo {
     #[doc = "test"]
         #[rustfmt::skip]
-        #[clippy::cyclomatic_complexity = "100"]
+        #[clippy::cognitive_complexity = "100"]
         bar: usize,
 }
 
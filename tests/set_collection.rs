@@ -0,0 +1,127 @@
+use std::{
+    collections::{
+        hash_map::{DefaultHasher, RandomState},
+        BTreeMap, HashMap, HashSet, VecDeque,
+    },
+    hash::BuildHasherDefault,
+};
+
+use getset2::{Getter, Setter};
+
+#[derive(Default)]
+pub struct Stack(Vec<usize>);
+
+impl Stack {
+    fn push(&mut self, value: usize) {
+        self.0.push(value)
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        self.0.pop()
+    }
+
+    fn retain<F: FnMut(&usize) -> bool>(&mut self, f: F) {
+        self.0.retain(f)
+    }
+
+    fn clear(&mut self) {
+        self.0.clear()
+    }
+}
+
+#[derive(Default, Getter, Setter)]
+#[get(pub)]
+#[set(pub, collection)]
+pub struct Foo {
+    #[get(slice)]
+    vec_field: Vec<usize>,
+    deque_field: VecDeque<usize>,
+    set_field: HashSet<String>,
+    map_field: HashMap<String, usize>,
+    #[set(owned)]
+    btree_map_field: BTreeMap<usize, String>,
+    #[get(skip)]
+    #[set(collection(seq(usize)))]
+    custom_field: Stack,
+}
+
+#[test]
+fn set_collection() {
+    let mut foo = Foo::default();
+
+    assert_eq!(
+        foo.push_vec_field(1)
+            .push_vec_field(2)
+            .push_vec_field(3)
+            .retain_vec_field(|&n| n != 2)
+            .vec_field(),
+        &[1, 3]
+    );
+    assert_eq!(foo.pop_vec_field(), Some(3));
+    assert!(foo.clear_vec_field().vec_field().is_empty());
+
+    foo.push_deque_field(1).push_deque_field(2);
+
+    assert_eq!(foo.pop_deque_field(), Some(2));
+
+    assert!(foo.insert_set_field("foo".to_string()));
+    assert!(!foo.insert_set_field("foo".to_string()));
+    assert!(foo.remove_set_field("foo"));
+    assert!(foo.set_field.is_empty());
+
+    assert_eq!(foo.insert_map_field("foo".to_string(), 1), None);
+    assert_eq!(foo.insert_map_field("foo".to_string(), 2), Some(1));
+    *foo.map_field_entry("bar".to_string()).or_insert(0) += 3;
+    foo.retain_map_field(|_, v| *v > 2);
+
+    assert_eq!(foo.remove_map_field("bar"), Some(3));
+    assert!(foo.map_field.is_empty());
+
+    let mut foo = Foo::default()
+        .with_btree_map_field(BTreeMap::from([(1, "foo".to_string())]))
+        .with_retain_btree_map_field(|&k, _| k > 1)
+        .with_clear_btree_map_field();
+
+    assert!(foo.btree_map_field.is_empty());
+    assert_eq!(foo.insert_btree_map_field(2, "bar".to_string()), None);
+    foo.btree_map_field_entry(2).or_default().push('!');
+    assert_eq!(foo.btree_map_field[&2], "bar!");
+
+    foo.push_custom_field(1)
+        .push_custom_field(2)
+        .retain_custom_field(|&n| n > 1);
+
+    assert_eq!(foo.pop_custom_field(), Some(2));
+    assert_eq!(foo.clear_custom_field().pop_custom_field(), None);
+}
+
+mod fast {
+    pub type HashMap<K, V> = std::collections::HashMap<K, V>;
+}
+
+#[derive(Default, Setter)]
+#[set(pub, collection)]
+pub struct Hashed<S> {
+    /// The entry setter of a map with a generic hasher
+    ///
+    /// `fn map_field_entry(&mut self, key: String) -> Entry<'_, String, usize> where String: Hash + Eq, S: BuildHasher`
+    map_field: HashMap<String, usize, S>,
+    set_field: std::collections::HashSet<usize, BuildHasherDefault<DefaultHasher>>,
+    /// A map of another crate has no entry setter
+    other_field: fast::HashMap<String, usize>,
+}
+
+#[test]
+fn set_collection_hasher() {
+    let mut foo = Hashed::<RandomState>::default();
+
+    *foo.map_field_entry("foo".to_string()).or_insert(1) += 1;
+    assert_eq!(foo.insert_map_field("bar".to_string(), 3), None);
+    assert_eq!(foo.remove_map_field("foo"), Some(2));
+
+    assert!(foo.insert_set_field(1));
+    assert!(foo.remove_set_field(&1));
+
+    assert_eq!(foo.insert_other_field("foo".to_string(), 1), None);
+    assert_eq!(foo.remove_other_field("foo"), Some(1));
+}
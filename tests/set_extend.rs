@@ -14,15 +14,19 @@ pub struct Foo<'a> {
     #[set(extend)]
     vec_field: Vec<usize>,
 
-    #[set(extend)]
+    #[set(extend(only))]
     map_field: HashMap<usize, usize>,
 
-    #[set(extend(&'a Path))]
+    #[set(extend(&'a Path, only))]
     path_field: PathBuf,
 
-    #[set(extend(P: AsRef<Path>))]
+    #[set(extend(P: AsRef<Path>, only))]
     p_field: PathBuf,
 
+    #[get(slice)]
+    #[set(extend, into)]
+    names: Vec<String>,
+
     #[get(skip)]
     #[set(skip)]
     phantom: PhantomData<&'a u8>,
@@ -33,19 +37,29 @@ fn set_extend() {
     let mut foo = Foo::default();
 
     assert_eq!(
-        foo.extend_string_field("foo".chars())
+        foo.set_string_field("bar".to_string())
+            .extend_string_field("foo".chars())
             .extend_string_field("bar".chars())
             .append_string_field('!')
             .string_field(),
-        "foobar!"
+        "barfoobar!"
     );
 
     assert_eq!(
-        foo.extend_vec_field([1, 2, 3])
+        foo.set_vec_field(vec![0])
+            .extend_vec_field([1, 2, 3])
             .extend_vec_field([4, 5, 6])
             .append_vec_field(7)
             .vec_field(),
-        [1, 2, 3, 4, 5, 6, 7]
+        [0, 1, 2, 3, 4, 5, 6, 7]
+    );
+
+    assert_eq!(
+        foo.set_names(vec!["foo".to_string()])
+            .extend_names(["bar", "baz"])
+            .append_names("qux")
+            .names(),
+        ["foo", "bar", "baz", "qux"]
     );
 
     assert_eq!(
@@ -69,5 +83,5 @@ fn set_extend() {
             .append_p_field("bar")
             .p_field(),
         Path::new("/foo/bar")
-    )
+    );
 }
@@ -0,0 +1,59 @@
+use getset2::{Getter, Setter};
+
+#[derive(Default, Getter, Setter)]
+#[get(pub)]
+#[set(pub)]
+pub struct NetConfig {
+    #[get(copy)]
+    port: u16,
+    #[get(str)]
+    host: String,
+}
+
+#[derive(Default, Getter, Setter)]
+#[get(pub)]
+#[set(pub, owned)]
+pub struct Server {
+    #[get(flatten(port: u16 copy, host: str))]
+    #[set(flatten(port: u16, host: String into))]
+    net: NetConfig,
+}
+
+#[test]
+fn set_flatten() {
+    let mut server = Server::default().with_port(80);
+
+    server.set_host("localhost");
+
+    assert_eq!(server.port(), 80);
+    assert_eq!(server.host(), "localhost");
+}
+
+mod proxy {
+    use getset2::{Getter, Setter};
+
+    #[derive(Default, Getter, Setter)]
+    #[get(pub, prefix = "get")]
+    #[set(pub, prefix = "put")]
+    pub struct Upstream {
+        #[get(copy, mut)]
+        port: u16,
+    }
+
+    #[derive(Default, Getter, Setter)]
+    pub struct Proxy {
+        #[get(flatten(prefix = "get", port: u16 copy mut))]
+        #[set(flatten(prefix = "put", port: u16))]
+        upstream: Upstream,
+    }
+}
+
+#[test]
+fn set_flatten_inner_prefix() {
+    let mut proxy = proxy::Proxy::default();
+
+    proxy.set_port(80);
+    *proxy.port_mut() += 8000;
+
+    assert_eq!(proxy.port(), 8080);
+}
@@ -2,6 +2,7 @@ use getset2::{Getter, Setter};
 
 #[derive(Default, Getter, Setter)]
 pub struct Foo {
+    #[allow(clippy::wrong_self_convention)]
     #[get(str)]
     #[set(into)]
     into_field: String,
@@ -4,6 +4,17 @@ use getset2::{Getter, Setter};
 pub struct Foo {
     #[set(opt)]
     option_field: Option<usize>,
+
+    #[set(opt(take, replace, clear), owned)]
+    utility_field: Option<String>,
+
+    #[get(opt)]
+    #[set(opt, into)]
+    path_field: Option<std::path::PathBuf>,
+
+    #[get(opt, copy)]
+    #[set(opt, try_into)]
+    byte_field: Option<u8>,
 }
 
 #[test]
@@ -12,3 +23,39 @@ fn set_opt() {
 
     assert_eq!(foo.set_option_field(123).option_field().unwrap(), 123);
 }
+
+#[test]
+fn set_opt_composed() {
+    let mut foo = Foo::default();
+
+    foo.set_path_field("/tmp");
+
+    assert_eq!(foo.path_field().unwrap(), std::path::Path::new("/tmp"));
+    assert_eq!(foo.set_byte_field(42u32).unwrap().byte_field(), Some(42));
+    assert!(foo.set_byte_field(256u32).is_err());
+    assert_eq!(foo.byte_field(), Some(42));
+}
+
+#[test]
+fn set_opt_utilities() {
+    let mut foo = Foo::default();
+
+    assert_eq!(foo.take_utility_field(), None);
+    assert_eq!(foo.replace_utility_field("foo".to_string()), None);
+    assert_eq!(
+        foo.replace_utility_field("bar".to_string()),
+        Some("foo".to_string())
+    );
+    assert_eq!(foo.take_utility_field(), Some("bar".to_string()));
+    assert_eq!(foo.utility_field(), &None);
+
+    foo.set_utility_field("baz".to_string());
+
+    assert!(foo.clear_utility_field().utility_field().is_none());
+
+    let foo = Foo::default()
+        .with_utility_field("foo".to_string())
+        .with_clear_utility_field();
+
+    assert!(foo.utility_field().is_none());
+}
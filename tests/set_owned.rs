@@ -0,0 +1,57 @@
+use getset2::{Getter, Setter};
+
+#[derive(Debug, Default, Getter, Setter)]
+#[set(pub, owned)]
+pub struct Config {
+    #[get(copy)]
+    port: u16,
+    #[get(str)]
+    #[set(into)]
+    host: String,
+    #[get(copy)]
+    #[set(try_into)]
+    timeout: u8,
+    #[get(opt)]
+    #[set(opt)]
+    user: Option<String>,
+    #[get(slice)]
+    #[set(extend)]
+    features: Vec<String>,
+}
+
+#[derive(Debug, Default, Getter, Setter)]
+pub struct Point {
+    #[get(copy)]
+    #[set(owned, prefix = "at", suffix = "pos")]
+    x: i32,
+    #[get(copy)]
+    y: i32,
+}
+
+#[test]
+fn set_owned() {
+    let config = Config::default()
+        .with_port(80)
+        .with_host("localhost")
+        .with_timeout(30u32)
+        .unwrap()
+        .with_user("admin".to_string())
+        .with_extend_features(["tls".to_string()])
+        .with_append_features("h2".to_string());
+
+    assert_eq!(config.port(), 80);
+    assert_eq!(config.host(), "localhost");
+    assert_eq!(config.timeout(), 30);
+    assert_eq!(config.user().map(String::as_str), Some("admin"));
+    assert_eq!(config.features(), ["tls", "h2"]);
+    assert!(Config::default().with_timeout(300u32).is_err());
+
+    let mut config = config;
+
+    assert_eq!(config.set_port(8080).port(), 8080);
+
+    let mut point = Point::default().at_with_x_pos(1);
+
+    assert_eq!(point.at_x_pos(2).x(), 2);
+    assert_eq!(point.set_y(3).y(), 3);
+}
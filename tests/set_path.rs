@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use getset2::{Getter, Setter};
+
+#[derive(Default)]
+pub struct Http {
+    timeout: Duration,
+    user_agent: String,
+}
+
+#[derive(Default)]
+pub struct Auth {
+    token: String,
+}
+
+#[derive(Default, Getter, Setter)]
+#[get(pub)]
+#[get(path(timeout: http.timeout as Duration, copy))]
+#[get(path(user_agent: http.user_agent, str))]
+#[get(path(token: auth?.token, str))]
+#[set(pub, owned)]
+#[set(path(timeout: http.timeout as Duration))]
+#[set(path(user_agent: http.user_agent as String, into))]
+#[set(path(token: auth?.token as String, into))]
+pub struct Config {
+    http: Http,
+    auth: Option<Auth>,
+}
+
+#[test]
+fn set_path() {
+    let mut config = Config::default().with_timeout(Duration::from_secs(30));
+
+    assert_eq!(config.token(), None);
+
+    config.set_user_agent("getset2").set_token("secret");
+
+    assert_eq!(config.timeout(), Duration::from_secs(30));
+    assert_eq!(config.user_agent(), "getset2");
+    assert_eq!(config.token(), Some("secret"));
+}
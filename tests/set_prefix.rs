@@ -0,0 +1,27 @@
+use getset2::{Getter, Setter};
+
+#[derive(Default, Getter, Setter)]
+#[set(prefix = "put")]
+pub struct Foo {
+    #[get(copy)]
+    port: u16,
+
+    #[set(extend)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn set_prefix() {
+    let mut foo = Foo::default();
+
+    foo.put_port(80)
+        .put_extend_tags(["a".to_string()])
+        .put_append_tags("b".to_string());
+
+    assert_eq!(foo.port(), 80);
+    assert_eq!(foo.tags(), &["a", "b"]);
+
+    foo.put_tags(vec!["c".to_string()]);
+
+    assert_eq!(foo.tags(), &["c"]);
+}
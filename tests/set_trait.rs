@@ -0,0 +1,69 @@
+use getset2::{Getter, Setter};
+
+#[derive(Default, Getter, Setter)]
+#[set(pub, owned, trait = FooMutators)]
+pub struct Foo {
+    #[get(copy)]
+    id: usize,
+    #[get(str)]
+    #[set(into)]
+    name: String,
+    #[get(slice)]
+    #[set(extend)]
+    tags: Vec<String>,
+    #[get(opt)]
+    #[set(opt(take))]
+    nick: Option<String>,
+}
+
+fn rename<T: FooMutators>(target: &mut T) {
+    target.set_id(42).set_name("bar");
+}
+
+fn take_nick(target: &mut dyn FooMutators) -> Option<String> {
+    target.take_nick()
+}
+
+#[test]
+fn set_trait() {
+    let mut foo = Foo::default()
+        .with_name("foo")
+        .with_nick("baz".to_string())
+        .with_append_tags("bar".to_string());
+
+    rename(&mut foo);
+
+    assert_eq!(foo.id(), 42);
+    assert_eq!(foo.name(), "bar");
+    assert_eq!(foo.tags(), ["bar"]);
+    assert_eq!(take_nick(&mut foo).unwrap(), "baz");
+    assert_eq!(foo.nick(), None);
+}
+
+#[derive(Default, Getter, Setter)]
+#[set(pub, trait = BarMutators, dyn)]
+pub struct Bar {
+    #[get(copy)]
+    id: usize,
+    #[get(str)]
+    #[set(into)]
+    name: String,
+}
+
+fn reset(target: &mut dyn BarMutators) {
+    target.set_id(0).set_id(1);
+}
+
+#[test]
+fn set_dyn_trait() {
+    let mut bar = Bar::default();
+
+    bar.set_name("bar").set_id(42);
+
+    assert_eq!(bar.id(), 42);
+    assert_eq!(bar.name(), "bar");
+
+    reset(&mut bar);
+
+    assert_eq!(bar.id(), 1);
+}
@@ -7,9 +7,61 @@ pub struct Foo {
     try_into_field: i32,
 }
 
+#[derive(Default, Getter, Setter)]
+#[set(into)]
+pub struct Bar {
+    #[get(copy)]
+    wide_field: i64,
+
+    // the field mode replaces the `into` of the structure
+    #[get(copy)]
+    #[set(try_into)]
+    try_into_field: u8,
+}
+
 #[test]
 fn set_try_into() {
     let mut foo = Foo::default();
 
     assert_eq!(foo.set_try_into_field(123).unwrap().try_into_field(), 123);
 }
+
+#[test]
+fn set_try_into_override() {
+    let mut bar = Bar::default();
+
+    assert_eq!(bar.set_wide_field(1i32).wide_field(), 1);
+    assert_eq!(bar.set_try_into_field(2i32).unwrap().try_into_field(), 2);
+    assert!(bar.set_try_into_field(256i32).is_err());
+}
+
+#[derive(Debug, Default, Setter)]
+#[set(error = wrap)]
+pub struct Baz {
+    #[set(try_into)]
+    try_into_field: u8,
+}
+
+mod rt {
+    pub use getset2 as getset;
+}
+
+#[derive(Debug, Default, Setter)]
+#[set(error = wrap, crate = crate::rt::getset)]
+pub struct Qux(#[set(try_into)] u8);
+
+#[test]
+fn set_try_into_wrap_error() {
+    let err = Baz::default().set_try_into_field(256i32).unwrap_err();
+
+    assert_eq!(err.field(), "try_into_field");
+    assert_eq!(*err.error(), u8::try_from(256i32).unwrap_err());
+
+    let err = Qux::default().set_arg0(-1i32).unwrap_err();
+
+    assert_eq!(err.field(), "0");
+    assert_eq!(
+        err.to_string(),
+        "invalid value of field `0`, out of range integral type conversion attempted"
+    );
+}